@@ -0,0 +1,11 @@
+use clap::ValueEnum;
+
+/// An accidental used to spell the notes represented by black keys
+/// on the piano, e.g. `C#` (sharp) or `Db` (flat).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Accidental {
+    /// Raise the white note below, e.g. C#
+    Sharp,
+    /// Lower the white note above, e.g. Db
+    Flat,
+}
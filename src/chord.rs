@@ -8,56 +8,330 @@ use std::{
 use itertools::Itertools;
 
 use crate::{
-    ChordType, NoMatchingChordTypeFoundError, Note, PitchClass, Semitones, UkeString, Voicing,
-    VoicingConfig, STRING_COUNT,
+    Accidental, ChordDetectionError, ChordType, Interval, Note, PitchClass, Quality, Semitones,
+    Shape, StaffSteps, UkeString, Voicing, VoicingConfig, PITCH_CLASS_COUNT, STRING_COUNT,
 };
 
 /// Custom error for strings that cannot be parsed into chords.
-#[derive(Debug, thiserror::Error)]
-#[error("could not parse chord name '{name}'")]
-pub struct ParseChordError {
-    name: String,
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ParseChordError {
+    /// The chord name does not start with a valid root note.
+    #[error("could not parse chord name '{name}': no valid root note found")]
+    InvalidRoot { name: String },
+    /// The root is valid, but the chord type symbol following it at
+    /// byte `position` is not. `suggestion` is the closest known symbol.
+    #[error(
+        "could not parse chord name '{name}': unknown chord type '{symbol}' at position {position}{}",
+        .suggestion.map(|s| format!("; did you mean '{s}'?")).unwrap_or_default()
+    )]
+    InvalidChordType {
+        name: String,
+        symbol: String,
+        position: usize,
+        suggestion: Option<&'static str>,
+    },
+}
+
+/// Custom error for interval lists that cannot be turned into a custom chord
+/// (see `Chord::from_intervals`).
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CustomChordError {
+    #[error("no intervals given")]
+    NoIntervals,
+    #[error("the intervals do not contain the root (P1)")]
+    MissingRoot,
 }
 
 /// A chord such as C, Cm and so on.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Chord {
     pub root: Note,
     pub chord_type: ChordType,
     pub notes: Vec<Note>,
+    /// The intervals between the root and the chord's notes.
+    pub intervals: Vec<Interval>,
 }
 
 impl Chord {
+    /// Create the chord of the given type on `root`, e.g. Cm for C and `Minor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chord_type` is `ChordType::Custom`, which has no intervals
+    /// of its own. Use `Chord::from_intervals` to create custom chords.
     pub fn new(root: Note, chord_type: ChordType) -> Self {
-        let notes = chord_type.intervals().map(|i| root + i).collect();
+        assert_ne!(
+            chord_type,
+            ChordType::Custom,
+            "custom chords need to be created from their intervals"
+        );
+        Self::with_intervals(root, chord_type, chord_type.intervals().collect())
+    }
+
+    /// Create a chord that is not one of the predefined chord types from
+    /// the intervals between its root and its notes, e.g. `P1 M3 A11` for
+    /// a major triad with a sharp eleventh instead of the fifth.
+    ///
+    /// All intervals are considered required notes. They have to include
+    /// the root itself (`P1`).
+    pub fn from_intervals(root: Note, intervals: &[Interval]) -> Result<Self, CustomChordError> {
+        if intervals.is_empty() {
+            return Err(CustomChordError::NoIntervals);
+        }
+
+        if !intervals.contains(&Interval::PerfectUnison) {
+            return Err(CustomChordError::MissingRoot);
+        }
+
+        Ok(Self::with_intervals(
+            root,
+            ChordType::Custom,
+            intervals.to_vec(),
+        ))
+    }
+
+    fn with_intervals(root: Note, chord_type: ChordType, intervals: Vec<Interval>) -> Self {
+        let notes = intervals.iter().map(|&i| root + i).collect();
         Self {
             root,
             chord_type,
             notes,
+            intervals,
         }
     }
 
+    /// Return an iterator over the intervals of the chord's notes that
+    /// cannot be left out when playing the chord.
+    fn required_intervals(&self) -> impl Iterator<Item = Interval> + '_ {
+        let optional: Vec<Interval> = self.chord_type.optional_intervals().collect();
+
+        self.intervals
+            .iter()
+            .copied()
+            .filter(move |i| !optional.contains(i))
+    }
+
     /// Return an iterator over the chord's notes that are played on our instrument.
     ///
     /// If the chord contains more notes than we have strings on our instrument,
     /// only required notes are played.
     pub fn played_notes(&self) -> impl Iterator<Item = Note> + '_ {
-        self.chord_type
-            .required_intervals()
-            .chain(self.chord_type.optional_intervals())
+        self.required_intervals()
+            .chain(
+                self.chord_type
+                    .optional_intervals()
+                    .filter(move |i| self.intervals.contains(i)),
+            )
             .take(STRING_COUNT)
             .map(move |i| self.root + i)
     }
 
+    /// Return an iterator over the chord's notes that cannot be left out
+    /// when playing the chord.
+    pub fn required_notes(&self) -> impl Iterator<Item = Note> + '_ {
+        self.required_intervals().map(move |i| self.root + i)
+    }
+
+    /// Return `true` if `note` is one of the chord's notes (in any spelling,
+    /// e.g. both D# and Eb are contained in Cm).
+    pub fn contains(&self, note: Note) -> bool {
+        self.notes.contains(&note)
+    }
+
+    /// Return `true` if both chords consist of the same pitch classes,
+    /// no matter how their roots are spelled and which of their notes is
+    /// considered the root, e.g. for C6 and Am7.
+    pub fn same_pitches(&self, other: &Chord) -> bool {
+        let pitch_classes = |chord: &Chord| -> Vec<PitchClass> {
+            chord
+                .notes
+                .iter()
+                .map(|n| n.pitch_class)
+                .sorted()
+                .dedup()
+                .collect()
+        };
+
+        pitch_classes(self) == pitch_classes(other)
+    }
+
+    /// Return the interval between the chord's root and `note` if `note` is
+    /// one of the chord's notes, e.g. M3 for E in C major.
+    pub fn degree_of(&self, note: Note) -> Option<Interval> {
+        self.intervals
+            .iter()
+            .zip(self.notes.iter())
+            .find(|(_i, &n)| n == note)
+            .map(|(&i, _n)| i)
+    }
+
+    /// Return the chord without its notes of the given interval number,
+    /// e.g. C(no3) consisting of C and G for C and 3. The chord type is kept,
+    /// so the omission shows up in the chord's name.
+    pub fn omit(&self, number: StaffSteps) -> Self {
+        let intervals = self
+            .intervals
+            .iter()
+            .copied()
+            .filter(|i| i.to_number() != number)
+            .collect();
+
+        Self::with_intervals(self.root, self.chord_type, intervals)
+    }
+
+    /// Return the numbers of the intervals of the chord type that have been
+    /// left out of the chord (see `omit`), e.g. `[3]` for C(no3).
+    fn omitted_numbers(&self) -> Vec<StaffSteps> {
+        self.chord_type
+            .intervals()
+            .filter(|i| !self.intervals.contains(i))
+            .map(|i| i.to_number())
+            .dedup()
+            .collect()
+    }
+
+    /// Return the chord's intervals in their canonical order, i.e. sorted by
+    /// the number of semitones (see the ordering of `Interval`), e.g.
+    /// `P1 M3 A11` for a chord created from the intervals `A11 P1 M3`.
+    pub fn sorted_intervals(&self) -> Vec<Interval> {
+        self.intervals.iter().copied().sorted().collect()
+    }
+
+    /// Return the chord's interval formula, e.g. `P1 M3 P5` for a major chord.
+    pub fn formula(&self) -> String {
+        self.intervals.iter().join(" ")
+    }
+
+    /// Return the coarse quality of the chord (major, minor, dominant etc.).
+    pub fn quality(&self) -> Quality {
+        Quality::from_intervals(&self.intervals)
+    }
+
+    /// Return a simpler version of the chord with the same root, e.g. C7 for C13.
+    /// See `ChordType::simplify` for how chord types are reduced.
+    /// Custom chords are returned unchanged.
+    pub fn simplify(&self) -> Self {
+        match self.chord_type {
+            ChordType::Custom => self.clone(),
+            chord_type => Self::new(self.root, chord_type.simplify()),
+        }
+    }
+
+    /// Return all voicings of the chord (see `voicings`), the ones closest
+    /// to the given moveable shape first. For this, the shape is moved up the
+    /// neck to the chord's root and each voicing is scored by how many frets
+    /// its strings differ from the moved shape, e.g. 5433 comes first for C
+    /// and the A shape.
+    pub fn voicings_by_shape(
+        &self,
+        config: VoicingConfig,
+        shape: Shape,
+    ) -> impl Iterator<Item = Voicing> + '_ {
+        let target = shape.moved_to(self.root, config.tuning, config.tuning_offset);
+
+        let shape_dist = move |voicing: &Voicing| -> u16 {
+            voicing
+                .frets()
+                .zip(target.iter())
+                .map(|(f1, &f2)| (f1 as i16 - f2 as i16).unsigned_abs())
+                .sum()
+        };
+
+        self.voicings(config)
+            .sorted_by_key(move |voicing| (shape_dist(voicing), *voicing))
+    }
+
+    /// Return the relative minor of a major chord, i.e. the minor chord whose
+    /// root is a minor third below, e.g. Am for C. Return `None` for other chord types.
+    pub fn relative_minor(&self) -> Option<Self> {
+        match self.chord_type {
+            ChordType::Major => Some(Self::new(
+                self.root + Interval::MajorSixth,
+                ChordType::Minor,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Return the relative major of a minor chord, i.e. the major chord whose
+    /// root is a minor third above, e.g. C for Am. Return `None` for other chord types.
+    pub fn relative_major(&self) -> Option<Self> {
+        match self.chord_type {
+            ChordType::Minor => Some(Self::new(
+                self.root + Interval::MinorThird,
+                ChordType::Major,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Return the parallel of a major or minor chord, i.e. the chord with the
+    /// same root and the opposite type, e.g. Cm for C and C for Cm.
+    /// Return `None` for other chord types.
+    pub fn parallel(&self) -> Option<Self> {
+        let chord_type = match self.chord_type {
+            ChordType::Major => ChordType::Minor,
+            ChordType::Minor => ChordType::Major,
+            _ => return None,
+        };
+
+        Some(Self::new(self.root, chord_type))
+    }
+
+    /// Return an iterator over all voicings of the chord that can be played
+    /// within the fret range `[config.min_fret, config.max_fret]` and with a span
+    /// of at most `config.max_span`.
+    ///
+    /// Open strings count as fret 0, so they are only used if `config.min_fret` is 0.
+    ///
+    /// As there are four strings, voicings of chords with fewer than four notes
+    /// double some of them. If `config.prefer_root_doubling` is set, the voicings
+    /// doubling the root come first. If `config.deny_duplicate_notes` is set,
+    /// voicings playing the same note in the same octave on two strings
+    /// (e.g. 0433 for C with G4 on both the G and the E string) are left out.
     pub fn voicings(&self, config: VoicingConfig) -> impl Iterator<Item = Voicing> + '_ {
-        config
+        self.unsorted_voicings(config)
+            .sorted_by_key(move |voicing| self.voicing_order(config, voicing))
+    }
+
+    /// Return the first voicing returned by `voicings` without sorting all of them,
+    /// e.g. to look up a single chord chart.
+    pub fn first_voicing(&self, config: VoicingConfig) -> Option<Voicing> {
+        self.unsorted_voicings(config)
+            .min_by_key(|voicing| self.voicing_order(config, voicing))
+    }
+
+    /// Return the key by which the voicings of the chord are sorted: lowest voicings
+    /// first, but voicings doubling the root before the others if
+    /// `config.prefer_root_doubling` is set.
+    fn voicing_order(&self, config: VoicingConfig, voicing: &Voicing) -> (bool, Voicing) {
+        let doubles_root = voicing.doubled_notes().contains(&self.root);
+        (config.prefer_root_doubling && !doubles_root, *voicing)
+    }
+
+    /// Return an iterator over the voicings of the chord (see `voicings`) in no
+    /// particular order.
+    fn unsorted_voicings(&self, config: VoicingConfig) -> impl Iterator<Item = Voicing> + '_ {
+        // The notes that may be played on the strings. If optional notes may be left out,
+        // each of them can take the place of another one.
+        let fretted: Vec<Note> = match config.allow_omissions {
+            true => self.notes.clone(),
+            false => self.played_notes().collect(),
+        };
+
+        let candidates: Vec<Vec<UkeString>> = config
             .tuning
             .roots()
+            .map(move |root| root.transpose(config.tuning_offset))
             // For each ukulele string, keep track of all the frets that when pressed down
             // while playing the string result in a note of the chord.
             .map(|root| {
-                self.played_notes()
-                    // Allow each note to be checked twice on the fretboard.
+                fretted
+                    .iter()
+                    .copied()
+                    // Allow each note to be checked twice on the fretboard. As a note's
+                    // first position is below fret 12, this covers all frets up to 23
+                    // (which is more than any ukulele has).
                     .cartesian_product(vec![0, 12])
                     // Determine the fret on which `note` is played.
                     .map(|(note, st)| (root, (note.pitch_class - root.pitch_class) + st, note))
@@ -65,15 +339,142 @@ impl Chord {
                     .filter(|(_r, fret, _n)| fret >= &config.min_fret && fret <= &config.max_fret)
                     .collect::<Vec<UkeString>>()
             })
-            // At this point, we have collected all possible positions of the notes in the chord
-            // on each ukulele string. Now let's check all combinations and determine the ones
-            // that result in a valid voicing of the chord.
-            .multi_cartesian_product()
+            .collect();
+
+        // The notes that each voicing has to contain.
+        let needed: Vec<Note> = match config.allow_omissions {
+            true => self.required_notes().collect(),
+            false => self.played_notes().collect(),
+        };
+
+        // At this point, we have collected all possible positions of the notes in the chord
+        // on each ukulele string. Now let's check the combinations that can still be played
+        // with the given span and contain all needed notes, and determine the ones that
+        // result in a valid voicing of the chord.
+        let mut combinations = vec![];
+        collect_combinations(
+            &candidates,
+            &needed,
+            config.max_span,
+            &mut Vec::with_capacity(STRING_COUNT),
+            &mut combinations,
+        );
+
+        combinations
+            .into_iter()
             // Create voicing from the UkeString vec.
-            .map(|us_vec| Voicing::from(&us_vec[..]))
+            .map(move |us_vec| {
+                Voicing::from(&us_vec[..]).with_octaves(
+                    config
+                        .tuning
+                        .octaves_with_offset(config.low_g, config.tuning_offset),
+                )
+            })
             // Keep only valid voicings.
-            .filter(|voicing| voicing.spells_out(self) && voicing.get_span() <= config.max_span)
-            .sorted()
+            .filter(move |voicing| {
+                let spells_out = match config.allow_omissions {
+                    true => voicing.spells_out_required(self),
+                    false => voicing.spells_out(self),
+                };
+
+                spells_out
+                    && voicing.playable(config.max_span).is_ok()
+                    && (!config.only_root_position || voicing.is_root_position(self))
+                    && (!config.deny_duplicate_notes || voicing.unisons().is_empty())
+            })
+    }
+
+    /// Return the voicing of the chord that is closest to `target`, e.g. to the shape
+    /// the player is currently holding. Voicings are compared by their distance to
+    /// `target` (see `Voicing::distance`). Ties are broken by preferring lower voicings.
+    pub fn closest_voicing(&self, config: VoicingConfig, target: &Voicing) -> Option<Voicing> {
+        self.voicings(config)
+            .min_by_key(|voicing| (target.distance(*voicing), *voicing))
+    }
+
+    /// Determine the chord made up of the given notes, e.g. C7 for C E G Bb.
+    ///
+    /// The first note is tried as the chord's root first, so if the notes
+    /// form several chords (such as C6 and Am7 for C E G A), the one with
+    /// this root is returned. Otherwise, the other notes are tried as roots
+    /// in the given order. The spelling of the root is kept.
+    pub fn from_notes(notes: &[Note]) -> Result<Self, ChordDetectionError> {
+        let notes: Vec<Note> = notes.iter().unique_by(|n| n.pitch_class).copied().collect();
+        let mut pitches: Vec<PitchClass> = notes.iter().map(|n| n.pitch_class).collect();
+        let mut err = ChordDetectionError::NotEnoughNotes;
+
+        for root in notes.iter() {
+            match ChordType::try_from(&pitches[..]) {
+                Ok(chord_type) => return Ok(Self::detected(*root, chord_type, &pitches)),
+                Err(e) => err = e,
+            }
+            pitches.rotate_left(1);
+        }
+
+        Err(err)
+    }
+
+    /// Return the chord of the given type that has been detected from `pitches`,
+    /// leaving out the required notes missing from them, e.g. C11(no3) for
+    /// C F G Bb D (see `ChordType::try_from`).
+    fn detected(root: Note, chord_type: ChordType, pitches: &[PitchClass]) -> Self {
+        let chord = Self::new(root, chord_type);
+        let missing: Vec<StaffSteps> = chord
+            .required_intervals()
+            .filter(|&i| !pitches.contains(&(root + i).pitch_class))
+            .map(|i| i.to_number())
+            .collect();
+
+        missing
+            .into_iter()
+            .fold(chord, |chord, number| chord.omit(number))
+    }
+
+    /// Return the voicing of the chord that is easiest to play according
+    /// to `Voicing::difficulty`. Ties are broken by preferring lower voicings.
+    pub fn best_voicing(&self, config: VoicingConfig) -> Option<Voicing> {
+        self.voicings(config)
+            .min_by_key(|voicing| (voicing.difficulty(), *voicing))
+    }
+
+    /// Return the chord's name made up of its root and the main symbol
+    /// of its chord type, e.g. "Cm7". Custom chords are named after their
+    /// root and intervals, e.g. "C(P1 M3 A11)". Omitted notes are appended,
+    /// e.g. "C(no3)".
+    pub fn name(&self) -> String {
+        match self.chord_type {
+            ChordType::Custom => format!("{}({})", self.root, self.formula()),
+            chord_type => {
+                let omissions: String = self
+                    .omitted_numbers()
+                    .iter()
+                    .map(|n| format!("(no{n})"))
+                    .collect();
+                format!("{}{}{}", self.root, chord_type.to_symbol(), omissions)
+            }
+        }
+    }
+
+    /// Return the other chords of the same type that consist of the same notes.
+    /// This is only the case for symmetric chord types such as diminished seventh
+    /// chords, e.g. Cdim7 has the equivalents D#dim7, F#dim7 and Adim7.
+    pub fn equivalents(&self) -> Vec<Self> {
+        if !self.chord_type.is_symmetric() {
+            return vec![];
+        }
+
+        let pitches: Vec<PitchClass> = self.notes.iter().map(|n| n.pitch_class).sorted().collect();
+
+        (1..PITCH_CLASS_COUNT)
+            .map(|n| self.clone() + n)
+            .filter(|c| {
+                c.notes
+                    .iter()
+                    .map(|n| n.pitch_class)
+                    .sorted()
+                    .eq(pitches.iter().copied())
+            })
+            .collect()
     }
 
     pub fn transpose(&self, semitones: i8) -> Self {
@@ -82,12 +483,101 @@ impl Chord {
             _ => self.clone() + semitones as Semitones,
         }
     }
+
+    /// Transpose the chord by `semitones` and spell its root with the
+    /// preferred accidental, e.g. `Gb` (not `F#`) for `F` transposed up by
+    /// one semitone when preferring flats.
+    pub fn transpose_spelled(&self, semitones: i8, prefer: Accidental) -> Self {
+        let root = self.root.transpose(semitones).spelled_with(prefer);
+        Self::with_intervals(root, self.chord_type, self.intervals.clone())
+    }
+
+    /// Return the chord with its root spelled with the preferred accidental,
+    /// e.g. `Bb` (not `A#`) when preferring flats. Chords whose root is a
+    /// white note are returned unchanged.
+    pub fn spelled_with(&self, prefer: Accidental) -> Self {
+        self.transpose_spelled(0, prefer)
+    }
+
+    /// Return the chord with each possible spelling of its root, the sharp one
+    /// first, e.g. `A#` and `Bb` for both `A#` and `Bb`, but just `C` for `C`.
+    /// This allows to choose the spelling that fits the key, e.g. for chords
+    /// detected from pitch classes, which are spelled with sharps by default.
+    pub fn enharmonic_root_options(&self) -> Vec<Self> {
+        [Accidental::Sharp, Accidental::Flat]
+            .iter()
+            .map(|&prefer| self.spelled_with(prefer))
+            .dedup_by(|c1, c2| c1.root.same_spelling(&c2.root))
+            .collect()
+    }
+}
+
+/// Collect all combinations of one `UkeString` per string (in the same order as
+/// `multi_cartesian_product`) that start with `prefix`. Combinations whose span
+/// exceeds `max_span` or that lack one of the `needed` notes are pruned as early
+/// as possible, i.e. as soon as the chosen strings span too many frets or too few
+/// strings are left to play the missing notes.
+fn collect_combinations(
+    candidates: &[Vec<UkeString>],
+    needed: &[Note],
+    max_span: Semitones,
+    prefix: &mut Vec<UkeString>,
+    combinations: &mut Vec<Vec<UkeString>>,
+) {
+    let frets = || prefix.iter().map(|&(_r, fret, _n)| fret);
+    let max_fret = frets().max().unwrap_or_default();
+    let min_pressed_fret = frets().filter(|&f| f > 0).min().unwrap_or(max_fret);
+
+    // The span can only grow when adding more strings.
+    if max_fret > 0 && max_fret - min_pressed_fret + 1 > max_span {
+        return;
+    }
+
+    // Each of the remaining strings can add at most one missing note.
+    let missing = needed
+        .iter()
+        .unique()
+        .filter(|&n| prefix.iter().all(|&(_r, _f, note)| note != *n))
+        .count();
+
+    if missing > candidates.len() - prefix.len() {
+        return;
+    }
+
+    match candidates.get(prefix.len()) {
+        Some(uke_strings) => {
+            for &uke_string in uke_strings {
+                prefix.push(uke_string);
+                collect_combinations(candidates, needed, max_span, prefix, combinations);
+                prefix.pop();
+            }
+        }
+        None => combinations.push(prefix.clone()),
+    }
 }
 
 impl fmt::Display for Chord {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = format!("{}{}", self.root, self.chord_type.to_symbol());
-        write!(f, "{name} - {} {}", self.root, self.chord_type)
+        write!(f, "{} - {} {}", self.name(), self.root, self.chord_type)?;
+
+        // Mention omitted notes in the long name as well, e.g. "C major (no 3rd)".
+        let omissions = self.omitted_numbers();
+
+        if !omissions.is_empty() {
+            let omissions = omissions.iter().map(|&n| format!("no {}", ordinal(n)));
+            write!(f, " ({})", omissions.format(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Return the ordinal of an interval number as used in chord names, e.g. "3rd".
+fn ordinal(number: StaffSteps) -> String {
+    match number {
+        2 => "2nd".to_string(),
+        3 => "3rd".to_string(),
+        n => format!("{n}th"),
     }
 }
 
@@ -95,6 +585,18 @@ impl FromStr for Chord {
     type Err = ParseChordError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Handle trailing modifiers such as `(no3)` for omitted notes.
+        if let Some((base, number)) = strip_omission(s) {
+            if let Ok(chord) = Self::from_str(base) {
+                if chord.intervals.iter().any(|i| i.to_number() == number) {
+                    return Ok(chord.omit(number));
+                }
+            }
+        }
+
+        // The position of the chord type symbol after the longest valid root found.
+        let mut position = None;
+
         // 1. Check the two first characters of the input string (for notes such as `C#`).
         // 2. Check only the first character (for notes such as `C`).
         for i in (1..3).rev() {
@@ -107,24 +609,54 @@ impl FromStr for Chord {
                             return Ok(Self::new(root, chord_type));
                         }
                     }
+
+                    position = position.or(Some(i));
                 }
             }
         }
 
         let name = s.to_string();
-        Err(ParseChordError { name })
+
+        match position {
+            Some(position) => {
+                let symbol = s[position..].to_string();
+                let suggestion = ChordType::closest_symbol(&symbol);
+                Err(ParseChordError::InvalidChordType {
+                    name,
+                    symbol,
+                    position,
+                    suggestion,
+                })
+            }
+            None => Err(ParseChordError::InvalidRoot { name }),
+        }
     }
 }
 
+/// Split a trailing modifier for an omitted note such as `(no3)` or `no5`
+/// off a chord name and return the rest of the name and the omitted
+/// interval number.
+fn strip_omission(s: &str) -> Option<(&str, StaffSteps)> {
+    let modifiers = [("(no3)", 3), ("no3", 3), ("(no5)", 5), ("no5", 5)];
+
+    modifiers.iter().find_map(|&(modifier, number)| {
+        let i = s.len().checked_sub(modifier.len())?;
+        match s.get(i..) {
+            Some(suffix) if suffix.eq_ignore_ascii_case(modifier) => Some((&s[..i], number)),
+            _ => None,
+        }
+    })
+}
+
 impl TryFrom<&[PitchClass]> for Chord {
-    type Error = NoMatchingChordTypeFoundError;
+    type Error = ChordDetectionError;
 
     /// Determine the chord that is represented by a list of pitch classes.
     fn try_from(pitches: &[PitchClass]) -> Result<Self, Self::Error> {
         let chord_type = ChordType::try_from(pitches)?;
         let root = Note::from(pitches[0]);
 
-        Ok(Self::new(root, chord_type))
+        Ok(Self::detected(root, chord_type, pitches))
     }
 }
 
@@ -132,7 +664,7 @@ impl Add<Semitones> for Chord {
     type Output = Self;
 
     fn add(self, n: Semitones) -> Self {
-        Self::new(self.root + n, self.chord_type)
+        Self::with_intervals(self.root + n, self.chord_type, self.intervals)
     }
 }
 
@@ -140,16 +672,22 @@ impl Sub<Semitones> for Chord {
     type Output = Self;
 
     fn sub(self, n: Semitones) -> Self {
-        Self::new(self.root - n, self.chord_type)
+        Self::with_intervals(self.root - n, self.chord_type, self.intervals)
     }
 }
 
 #[cfg(test)]
+// The parsing tests for 11th and 13th chords take one column per note (plus the
+// chord name), and rstest does not pass attributes on to the test functions.
+#[allow(clippy::too_many_arguments)]
 mod tests {
+    use std::{collections::HashSet, hint::black_box, time::Instant};
+
     use rstest::rstest;
     use PitchClass::*;
 
     use super::*;
+    use crate::{FretID, Tuning, MAX_FRET_ID};
 
     #[rstest(
         chord,
@@ -159,12 +697,61 @@ mod tests {
         case("C#mb5"),
         case("C#mbla"),
         case("CmMaj"),
-        case("CmMaj7b5")
+        case("CmMaj7b5"),
+        case("Cmajor9"),
+        case("CMM7")
     )]
     fn test_from_str_fail(chord: &str) {
         assert!(Chord::from_str(chord).is_err());
     }
 
+    #[rstest(
+        chord,
+        error,
+        case("Z", "could not parse chord name 'Z': no valid root note found"),
+        case(
+            "Cmin7b",
+            "could not parse chord name 'Cmin7b': unknown chord type 'min7b' at position 1; did you mean 'min7'?"
+        ),
+        case(
+            "C#mj7",
+            "could not parse chord name 'C#mj7': unknown chord type 'mj7' at position 2; did you mean 'maj7'?"
+        ),
+        case(
+            "Cxyz",
+            "could not parse chord name 'Cxyz': unknown chord type 'xyz' at position 1"
+        ),
+    )]
+    fn test_from_str_fail_message(chord: &str, error: &str) {
+        let err = Chord::from_str(chord).unwrap_err();
+        assert_eq!(err.to_string(), error);
+    }
+
+    #[rstest(
+        chord,
+        name,
+        case("CDIM7", "Cdim7"),
+        case("CSus4", "Csus4"),
+        case("CMAJ7", "Cmaj7"),
+        case("CM7", "Cmaj7"),
+        case("Cm7", "Cm7"),
+        case("CmMaj7", "CmMaj7"),
+        case("CmMAJ7", "CmMaj7"),
+        case("BbADD9", "Bbadd9"),
+        case("BAug", "Baug"),
+        case("Cmaj", "C"),
+        case("CMaj", "C"),
+        case("CM", "C"),
+        case("Cmajor", "C"),
+        case("BbMajor", "Bb"),
+        case("CMaj7", "Cmaj7"),
+        case("Cmajor7", "Cmaj7"),
+        case("F#MAJOR7", "F#maj7")
+    )]
+    fn test_from_str_case_insensitive(chord: Chord, name: &str) {
+        assert_eq!(chord.name(), name);
+    }
+
     #[rstest(
         chord_base,
         root,
@@ -1531,6 +2118,43 @@ mod tests {
         assert_eq!(Chord::try_from(&pitches[..]).unwrap(), chord);
     }
 
+    #[test]
+    fn test_get_chord_type_round_trip() {
+        // C F G Bb D form a dominant eleventh chord without its third.
+        let pitches = vec![C, F, G, ASharp, D];
+        let name = "C11(no3)";
+
+        let chord = Chord::try_from(&pitches[..]).unwrap();
+        assert_eq!(chord.name(), name);
+
+        // The chord named after the notes consists of exactly these notes.
+        let chord = Chord::from_str(name).unwrap();
+        let pcs: HashSet<PitchClass> = chord.notes.iter().map(|n| n.pitch_class).collect();
+        assert_eq!(pcs, pitches.into_iter().collect());
+
+        // The voicing named after the chord is one of its voicings.
+        let voicing = Voicing::new([3, 2, 1, 3], Tuning::C); // Bb D F C
+        assert!(voicing.get_chords().contains(&chord));
+
+        let config = VoicingConfig {
+            allow_omissions: true,
+            ..Default::default()
+        };
+        assert!(chord.voicings(config).any(|v| v == voicing));
+    }
+
+    #[rstest(
+        pitches,
+        error,
+        case(vec![], ChordDetectionError::NotEnoughNotes),
+        case(vec![C], ChordDetectionError::NotEnoughNotes),
+        case(vec![C, E], ChordDetectionError::NoMatchingType),
+        case(vec![C, CSharp, D, DSharp], ChordDetectionError::NoMatchingType),
+    )]
+    fn test_get_chord_type_error(pitches: Vec<PitchClass>, error: ChordDetectionError) {
+        assert_eq!(Chord::try_from(&pitches[..]).unwrap_err(), error);
+    }
+
     #[rstest(
         chord1,
         n,
@@ -1595,6 +2219,56 @@ mod tests {
         assert_eq!(chord1.transpose(n), chord2);
     }
 
+    #[rstest(
+        chord1,
+        n,
+        prefer,
+        chord2,
+        notes,
+        case("F", 1, Accidental::Flat, "Gb", "Gb Bb Db"),
+        case("F", 1, Accidental::Sharp, "F#", "F# A# C#"),
+        case("Db", 11, Accidental::Flat, "C", "C E G"),
+        case("Db", 11, Accidental::Sharp, "C", "C E G"),
+        case("Cm7", -2, Accidental::Flat, "Bbm7", "Bb Db F Ab"),
+        case("Cm7", -2, Accidental::Sharp, "A#m7", "A# C# F G#"),
+        case("G#", 0, Accidental::Flat, "Ab", "Ab C Eb"),
+        case("E", 1, Accidental::Flat, "F", "F A C")
+    )]
+    fn test_transpose_spelled(chord1: Chord, n: i8, prefer: Accidental, chord2: &str, notes: &str) {
+        let chord = chord1.transpose_spelled(n, prefer);
+        assert_eq!(chord.name(), chord2);
+        assert_eq!(chord.notes.iter().join(" "), notes);
+    }
+
+    #[rstest(
+        chord,
+        names,
+        case("C", vec!["C"]),
+        case("A#", vec!["A#", "Bb"]),
+        case("Bb", vec!["A#", "Bb"]),
+        case("Ebm7", vec!["D#m7", "Ebm7"]),
+        case("F#(no3)", vec!["F#(no3)", "Gb(no3)"])
+    )]
+    fn test_enharmonic_root_options(chord: Chord, names: Vec<&str>) {
+        let options: Vec<String> = chord
+            .enharmonic_root_options()
+            .iter()
+            .map(|c| c.name())
+            .collect();
+        assert_eq!(options, names);
+    }
+
+    #[test]
+    fn test_enharmonic_root_options_detected() {
+        let chord = Chord::try_from(&[ASharp, D, F][..]).unwrap();
+        assert_eq!(chord.to_string(), "A# - A# major");
+
+        let options = chord.enharmonic_root_options();
+        assert_eq!(options[0].to_string(), "A# - A# major");
+        assert_eq!(options[1].to_string(), "Bb - Bb major");
+        assert_eq!(options[1].notes.iter().join(" "), "Bb D F");
+    }
+
     #[rstest(
         chord,
         played_notes,
@@ -1612,4 +2286,761 @@ mod tests {
 
         assert_eq!(pn1, pn2);
     }
+
+    #[rstest(
+        chord, frets, strict, relaxed,
+        case("C7", [0, 0, 0, 1], true, true), // G C E A#
+        case("C7", [5, 4, 6, 3], false, true), // C E A# C (no 5th)
+        case("C13", [2, 0, 0, 1], true, true), // A C E A# (no 5th)
+        case("C13", [0, 0, 0, 1], false, false), // G C E A# (no 13th)
+        case("Csus4", [5, 5, 8, 8], false, true), // C F C F (no 5th)
+        case("Csus2", [7, 0, 8, 5], false, true), // D C C D (no 5th)
+    )]
+    fn test_voicings_allow_omissions(
+        chord: Chord,
+        frets: [FretID; STRING_COUNT],
+        strict: bool,
+        relaxed: bool,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+
+        let config = VoicingConfig::default();
+        assert_eq!(chord.voicings(config).any(|v| v == voicing), strict);
+
+        let config = VoicingConfig {
+            allow_omissions: true,
+            ..Default::default()
+        };
+        assert_eq!(chord.voicings(config).any(|v| v == voicing), relaxed);
+    }
+
+    #[rstest(
+        chord,
+        frets,
+        case("C5", [0, 0, 3, 3]), // G C G C
+        case("G5", [0, 2, 3, 5]), // G D G D
+        case("A5", [2, 4, 0, 0]), // A E E A
+    )]
+    fn test_voicings_power_chord(chord: Chord, frets: [FretID; STRING_COUNT]) {
+        let voicing = chord.voicings(VoicingConfig::default()).next().unwrap();
+        assert_eq!(voicing, Voicing::new(frets, Tuning::C));
+
+        // All voicings use all the strings, doubling the root and/or the fifth.
+        for voicing in chord.voicings(VoicingConfig::default()) {
+            assert_eq!(voicing.notes().count(), STRING_COUNT);
+            assert!(voicing.notes().all(|n| chord.notes.contains(&n)));
+        }
+    }
+
+    #[rstest(
+        chord,
+        name,
+        long_name,
+        notes,
+        case("C(no3)", "C(no3)", "C major (no 3rd)", "C G"),
+        case("Cno3", "C(no3)", "C major (no 3rd)", "C G"),
+        case("G7(no5)", "G7(no5)", "G dominant 7th (no 5th)", "G B F"),
+        case("G7NO5", "G7(no5)", "G dominant 7th (no 5th)", "G B F"),
+        case("Am(no5)", "Am(no5)", "A minor (no 5th)", "A C"),
+        case("C(no3)(no5)", "C(no3)(no5)", "C major (no 3rd, no 5th)", "C")
+    )]
+    fn test_from_str_omission(chord: Chord, name: &str, long_name: &str, notes: &str) {
+        assert_eq!(chord.name(), name);
+        assert_eq!(chord.to_string(), format!("{name} - {long_name}"));
+        assert_eq!(chord.notes.iter().join(" "), notes);
+        assert_eq!(chord.required_notes().join(" "), notes);
+        assert_eq!(chord.played_notes().join(" "), notes);
+        assert_eq!(Chord::from_str(name).unwrap(), chord);
+    }
+
+    #[rstest(
+        chord,
+        case("C5(no3)"),
+        case("Csus4no3"),
+        case("C(no7)"),
+        case("(no3)")
+    )]
+    fn test_from_str_omission_fail(chord: &str) {
+        assert!(Chord::from_str(chord).is_err());
+    }
+
+    #[test]
+    fn test_voicings_omission() {
+        let chord = Chord::from_str("C(no3)").unwrap();
+        let power_chord = Chord::from_str("C5").unwrap();
+        let config = VoicingConfig::default();
+
+        // C(no3) is effectively a power chord: only C and G are played.
+        assert!(chord
+            .voicings(config)
+            .all(|v| v.notes().all(|n| power_chord.contains(n))));
+        assert!(chord.voicings(config).eq(power_chord.voicings(config)));
+    }
+
+    /// Compute the voicings of `chord` by checking all combinations of frets,
+    /// i.e. without pruning them early, to compare `Chord::voicings` against.
+    fn voicings_unpruned(chord: &Chord, config: VoicingConfig) -> Vec<Voicing> {
+        config
+            .tuning
+            .roots()
+            .map(|root| root.transpose(config.tuning_offset))
+            .map(|root| {
+                chord
+                    .played_notes()
+                    .cartesian_product(vec![0, 12])
+                    .map(|(note, st)| (root, (note.pitch_class - root.pitch_class) + st, note))
+                    .filter(|(_r, fret, _n)| fret >= &config.min_fret && fret <= &config.max_fret)
+                    .collect::<Vec<UkeString>>()
+            })
+            .multi_cartesian_product()
+            .map(|us_vec| {
+                Voicing::from(&us_vec[..]).with_octaves(
+                    config
+                        .tuning
+                        .octaves_with_offset(config.low_g, config.tuning_offset),
+                )
+            })
+            .filter(|voicing| {
+                let spells_out = match config.allow_omissions {
+                    true => voicing.spells_out_required(chord),
+                    false => voicing.spells_out(chord),
+                };
+
+                spells_out && voicing.playable(config.max_span).is_ok()
+            })
+            .sorted()
+            .collect()
+    }
+
+    #[rstest(
+        allow_omissions,
+        tuning,
+        min_fret,
+        max_fret,
+        max_span,
+        case(false, Tuning::C, 0, 12, 4),
+        case(true, Tuning::C, 0, 12, 4),
+        case(false, Tuning::G, 3, 15, 5),
+        case(true, Tuning::D, 0, 7, 2),
+        case(false, Tuning::C, 0, 21, 0)
+    )]
+    fn test_voicings_pruned(
+        allow_omissions: bool,
+        tuning: Tuning,
+        min_fret: FretID,
+        max_fret: FretID,
+        max_span: Semitones,
+    ) {
+        let config = VoicingConfig {
+            allow_omissions,
+            tuning,
+            min_fret,
+            max_fret,
+            max_span,
+            ..Default::default()
+        };
+
+        for root in ["C", "F#", "Bb"] {
+            for chord_type in ChordType::values() {
+                let chord = Chord::new(root.parse().unwrap(), chord_type);
+                let voicings: Vec<Voicing> = chord.voicings(config).collect();
+                assert_eq!(voicings, voicings_unpruned(&chord, config), "{chord}");
+            }
+        }
+    }
+
+    /// Compare the time needed to compute the voicings of C13 with and without
+    /// pruning. Run with `cargo test --release -- --ignored --nocapture bench_`.
+    #[test]
+    #[ignore]
+    fn bench_voicings_pruned() {
+        let chord = Chord::from_str("C13").unwrap();
+        let config = VoicingConfig {
+            max_fret: MAX_FRET_ID,
+            ..Default::default()
+        };
+        let runs = 10;
+
+        let start = Instant::now();
+        for _ in 0..runs {
+            black_box(voicings_unpruned(&chord, config));
+        }
+        let unpruned = start.elapsed() / runs;
+
+        let start = Instant::now();
+        for _ in 0..runs {
+            black_box(chord.voicings(config).collect::<Vec<_>>());
+        }
+        let pruned = start.elapsed() / runs;
+
+        println!("C13 voicings: {unpruned:?} without pruning, {pruned:?} with pruning");
+    }
+
+    #[rstest(
+        chord,
+        shape,
+        frets,
+        case("C", Shape::C, [0, 0, 0, 3]),
+        case("C", Shape::A, [5, 4, 3, 3]),
+        case("C", Shape::G, [5, 7, 8, 7]),
+        case("C", Shape::F, [9, 7, 8, 7]),
+        case("C", Shape::D, [12, 12, 12, 10]),
+        case("Cm", Shape::A, [5, 3, 3, 3]),
+        case("D", Shape::C, [2, 2, 2, 5])
+    )]
+    fn test_voicings_by_shape(chord: Chord, shape: Shape, frets: [FretID; STRING_COUNT]) {
+        let config = VoicingConfig::default();
+        let voicing = chord.voicings_by_shape(config, shape).next().unwrap();
+        assert_eq!(voicing.fret_array(), frets);
+
+        // All voicings are still returned, only in a different order.
+        assert_eq!(
+            chord.voicings_by_shape(config, shape).count(),
+            chord.voicings(config).count()
+        );
+    }
+
+    #[rstest(
+        root,
+        intervals,
+        name,
+        notes,
+        case("C", vec!["P1", "M3", "A11"], "C(P1 M3 A11)", "C E F#"),
+        case("D", vec!["P1", "M3", "A11"], "D(P1 M3 A11)", "D F# G#"),
+        case("A", vec!["P1", "P4", "m7", "M9"], "A(P1 P4 m7 M9)", "A D G B")
+    )]
+    fn test_from_intervals(root: Note, intervals: Vec<&str>, name: &str, notes: &str) {
+        let intervals: Vec<Interval> = intervals.iter().map(|i| i.parse().unwrap()).collect();
+        let chord = Chord::from_intervals(root, &intervals).unwrap();
+
+        assert_eq!(chord.chord_type, ChordType::Custom);
+        assert_eq!(chord.name(), name);
+        assert_eq!(chord.to_string(), format!("{name} - {root} custom"));
+        assert_eq!(chord.notes.iter().join(" "), notes);
+        assert_eq!(chord.required_notes().join(" "), notes);
+        assert_eq!(chord.simplify(), chord);
+        assert!(chord.equivalents().is_empty());
+    }
+
+    #[rstest(
+        intervals,
+        error,
+        case(vec![], CustomChordError::NoIntervals),
+        case(vec!["M3", "P5"], CustomChordError::MissingRoot),
+        case(vec!["P4", "m7", "M9"], CustomChordError::MissingRoot)
+    )]
+    fn test_from_intervals_error(intervals: Vec<&str>, error: CustomChordError) {
+        let intervals: Vec<Interval> = intervals.iter().map(|i| i.parse().unwrap()).collect();
+        let root = Note::from_str("C").unwrap();
+        assert_eq!(Chord::from_intervals(root, &intervals).unwrap_err(), error);
+    }
+
+    #[test]
+    #[should_panic(expected = "custom chords need to be created from their intervals")]
+    fn test_new_custom() {
+        let root = Note::from_str("C").unwrap();
+        Chord::new(root, ChordType::Custom);
+    }
+
+    #[rstest(
+        root,
+        intervals,
+        sorted,
+        case("C", vec!["A11", "P1", "M3"], vec!["P1", "M3", "A11"]),
+        case("C", vec!["P1", "M6", "d7", "m3"], vec!["P1", "m3", "M6", "d7"]),
+        case("D", vec!["m6", "A5", "P1"], vec!["P1", "A5", "m6"])
+    )]
+    fn test_sorted_intervals(root: Note, intervals: Vec<&str>, sorted: Vec<&str>) {
+        let intervals: Vec<Interval> = intervals.iter().map(|i| i.parse().unwrap()).collect();
+        let chord = Chord::from_intervals(root, &intervals).unwrap();
+        assert_eq!(chord.sorted_intervals().iter().join(" "), sorted.join(" "));
+    }
+
+    #[test]
+    fn test_sorted_intervals_ascending() {
+        let root = Note::from_str("C").unwrap();
+
+        for chord_type in ChordType::values() {
+            let chord = Chord::new(root, chord_type);
+            let sorted = chord.sorted_intervals();
+
+            assert_eq!(sorted.len(), chord.intervals.len());
+            assert!(sorted
+                .windows(2)
+                .all(|w| w[0].to_semitones() <= w[1].to_semitones()));
+        }
+    }
+
+    #[test]
+    fn test_from_intervals_voicings() {
+        use Interval::*;
+
+        let root = Note::from_str("C").unwrap();
+        let chord =
+            Chord::from_intervals(root, &[PerfectUnison, MajorThird, AugmentedEleventh]).unwrap();
+        let config = VoicingConfig::default();
+
+        let voicing = chord.voicings(config).next().unwrap();
+        assert_eq!(voicing.fret_array(), [5, 4, 2, 3]);
+
+        // Every voicing contains all notes of the chord, and nothing else.
+        for voicing in chord.voicings(config) {
+            let notes: HashSet<Note> = voicing.notes().collect();
+            assert_eq!(notes, chord.notes.iter().copied().collect());
+        }
+
+        // Transposing the chord keeps its intervals.
+        let transposed = chord.transpose(2);
+        assert_eq!(transposed.name(), "D(P1 M3 A11)");
+        assert_eq!(transposed.intervals, chord.intervals);
+        assert_eq!(transposed.quality(), Quality::Major);
+    }
+
+    #[rstest(
+        major,
+        minor,
+        case("C", "Am"),
+        case("G", "Em"),
+        case("F", "Dm"),
+        case("Eb", "Cm"),
+        case("Db", "Bbm"),
+        case("E", "C#m"),
+        case("F#", "D#m")
+    )]
+    fn test_relative_minor_and_major(major: Chord, minor: Chord) {
+        let relative_minor = major.relative_minor().unwrap();
+        assert_eq!(relative_minor.name(), minor.name());
+
+        let relative_major = minor.relative_major().unwrap();
+        assert_eq!(relative_major.name(), major.name());
+    }
+
+    #[rstest(chord1, chord2, case("C", "Cm"), case("Bb", "Bbm"), case("F#", "F#m"))]
+    fn test_parallel(chord1: Chord, chord2: Chord) {
+        assert_eq!(chord1.parallel().unwrap().name(), chord2.name());
+        assert_eq!(chord2.parallel().unwrap().name(), chord1.name());
+    }
+
+    #[rstest(
+        chord,
+        case("C7"),
+        case("Cdim"),
+        case("Cmaj7"),
+        case("Am7"),
+        case("Csus2")
+    )]
+    fn test_relative_and_parallel_none(chord: Chord) {
+        assert_eq!(chord.relative_minor(), None);
+        assert_eq!(chord.relative_major(), None);
+        assert_eq!(chord.parallel(), None);
+    }
+
+    #[rstest(
+        chord1,
+        chord2,
+        same_pitches,
+        equal,
+        // Notes (and hence chords) are compared by pitch class, so the
+        // spelling of the root does not matter.
+        case("C#", "Db", true, true),
+        case("D#m7", "Ebm7", true, true),
+        case("C6", "Am7", true, false),
+        case("Cdim7", "Adim7", true, false),
+        case("C", "Cm", false, false),
+        case("C", "C7", false, false)
+    )]
+    fn test_same_pitches(chord1: Chord, chord2: Chord, same_pitches: bool, equal: bool) {
+        assert_eq!(chord1.same_pitches(&chord2), same_pitches);
+        assert_eq!(chord2.same_pitches(&chord1), same_pitches);
+        assert_eq!(chord1 == chord2, equal);
+    }
+
+    #[rstest(
+        chord,
+        note,
+        degree,
+        case("C", "C", Some("P1")),
+        case("C", "E", Some("M3")),
+        case("C", "G", Some("P5")),
+        case("C", "D", None),
+        case("Cm", "D#", Some("m3")),
+        case("Cm", "Eb", Some("m3")),
+        case("C9", "D", Some("M9")),
+        case("C7", "B", None)
+    )]
+    fn test_contains_and_degree_of(chord: Chord, note: Note, degree: Option<&str>) {
+        let degree = degree.map(|d| Interval::from_str(d).unwrap());
+        assert_eq!(chord.contains(note), degree.is_some());
+        assert_eq!(chord.degree_of(note), degree);
+    }
+
+    #[rstest(
+        chord,
+        offset,
+        frets,
+        case("C", 0, [0, 0, 0, 3]),
+        case("B", -1, [0, 0, 0, 3]),
+        case("C", -1, [1, 1, 1, 4]),
+        case("D", 2, [0, 0, 0, 3]),
+    )]
+    fn test_voicings_tuning_offset(chord: Chord, offset: i8, frets: [FretID; STRING_COUNT]) {
+        let config = VoicingConfig {
+            tuning_offset: offset,
+            ..Default::default()
+        };
+        let voicing = chord.voicings(config).next().unwrap();
+        assert_eq!(voicing.fret_array(), frets);
+    }
+
+    #[rstest(
+        notes,
+        chord,
+        case("C E G Bb", "C7"),
+        case("C E G", "C"),
+        case("E G C", "C"),
+        case("Eb Gb Bb", "Ebm"),
+        case("D# F# A#", "D#m"),
+        case("Bb D F A", "Bbmaj7"),
+        // The first note is preferred as the root.
+        case("C E G A", "C6"),
+        case("A C E G", "Am7"),
+        // Duplicate notes are ignored.
+        case("C E G C E", "C"),
+    )]
+    fn test_from_notes(notes: &str, chord: &str) {
+        let notes: Vec<Note> = notes
+            .split_whitespace()
+            .map(|n| Note::from_str(n).unwrap())
+            .collect();
+        let c = Chord::from_notes(&notes).unwrap();
+        assert_eq!(c.name(), chord);
+    }
+
+    #[rstest(
+        notes,
+        error,
+        case("", ChordDetectionError::NotEnoughNotes),
+        case("C", ChordDetectionError::NotEnoughNotes),
+        case("C C", ChordDetectionError::NotEnoughNotes),
+        case("C C# D", ChordDetectionError::NoMatchingType)
+    )]
+    fn test_from_notes_fail(notes: &str, error: ChordDetectionError) {
+        let notes: Vec<Note> = notes
+            .split_whitespace()
+            .map(|n| Note::from_str(n).unwrap())
+            .collect();
+        assert_eq!(Chord::from_notes(&notes).unwrap_err(), error);
+    }
+
+    #[rstest(
+        chord,
+        case("C"),
+        case("Am"),
+        case("G7"),
+        case("C#maj7"),
+        case("Bbm7b5"),
+        case("F#13"),
+        case("Ebadd9"),
+        case("C(no3)")
+    )]
+    fn test_first_voicing(chord: Chord) {
+        let configs = [
+            VoicingConfig::default(),
+            VoicingConfig {
+                prefer_root_doubling: true,
+                ..Default::default()
+            },
+            VoicingConfig {
+                tuning: Tuning::G,
+                min_fret: 5,
+                ..Default::default()
+            },
+            VoicingConfig {
+                allow_omissions: true,
+                max_span: 2,
+                ..Default::default()
+            },
+            VoicingConfig {
+                max_fret: 0,
+                ..Default::default()
+            },
+        ];
+
+        for config in configs {
+            assert_eq!(chord.first_voicing(config), chord.voicings(config).next());
+        }
+    }
+
+    #[rstest(
+        chord,
+        best,
+        case("C", [0, 0, 0, 3]),
+        case("G", [0, 2, 3, 2]),
+        // The lowest voicing 2413 needs all four fingers.
+        case("Fmaj7", [5, 5, 0, 0]),
+        case("Abmaj7", [0, 3, 4, 3]),
+    )]
+    fn test_best_voicing(chord: Chord, best: [FretID; STRING_COUNT]) {
+        let config = VoicingConfig::default();
+        let voicing = chord.best_voicing(config).unwrap();
+        assert_eq!(voicing.fret_array(), best);
+
+        // No other voicing is easier to play.
+        assert!(chord
+            .voicings(config)
+            .all(|v| v.difficulty() >= voicing.difficulty()));
+    }
+
+    #[rstest(
+        chord,
+        target,
+        closest,
+        // G -> C: keep the fingers close to the G shape.
+        case("C", [0, 2, 3, 2], [0, 4, 3, 3]),
+        case("C", [5, 4, 3, 3], [5, 4, 3, 3]),
+        case("F", [0, 2, 3, 2], [2, 0, 1, 3]),
+        case("G", [7, 7, 7, 7], [7, 7, 7, 5]),
+    )]
+    fn test_closest_voicing(
+        chord: Chord,
+        target: [FretID; STRING_COUNT],
+        closest: [FretID; STRING_COUNT],
+    ) {
+        let config = VoicingConfig::default();
+        let target = Voicing::new(target, Tuning::C);
+        let voicing = chord.closest_voicing(config, &target).unwrap();
+        assert_eq!(voicing.fret_array(), closest);
+
+        // No other voicing has a smaller semitone distance.
+        let min_dist = chord
+            .voicings(config)
+            .map(|v| target.semitone_distance(v))
+            .min()
+            .unwrap();
+        assert_eq!(target.semitone_distance(voicing), min_dist);
+    }
+
+    #[test]
+    fn test_voicings_doubling() {
+        let chord = Chord::from_str("C").unwrap();
+        let root = Note::from_str("C").unwrap();
+        let fifth = Note::from_str("G").unwrap();
+
+        // The triad is voiced both with a doubled root and with a doubled fifth.
+        let doubled: Vec<Vec<Note>> = chord
+            .voicings(VoicingConfig::default())
+            .map(|v| v.doubled_notes())
+            .collect();
+        assert!(doubled.contains(&vec![root]));
+        assert!(doubled.contains(&vec![fifth]));
+        assert!(doubled.iter().all(|d| d.len() == 1));
+
+        let config = VoicingConfig {
+            prefer_root_doubling: true,
+            ..Default::default()
+        };
+        let doubles_root: Vec<bool> = chord
+            .voicings(config)
+            .map(|v| v.doubled_notes().contains(&root))
+            .collect();
+        assert!(doubles_root.contains(&false));
+        assert!(doubles_root.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_voicings_deny_duplicate_notes() {
+        let chord = Chord::from_str("C").unwrap();
+        let all: Vec<Voicing> = chord.voicings(VoicingConfig::default()).collect();
+
+        let config = VoicingConfig {
+            deny_duplicate_notes: true,
+            ..Default::default()
+        };
+        let distinct: Vec<Voicing> = chord.voicings(config).collect();
+
+        // 0433 plays G4 on both the G and the E string.
+        assert!(all.contains(&Voicing::new([0, 4, 3, 3], Tuning::C)));
+        assert!(!distinct.contains(&Voicing::new([0, 4, 3, 3], Tuning::C)));
+        // 0003 doubles the root C an octave apart, which is fine.
+        assert!(distinct.contains(&Voicing::new([0, 0, 0, 3], Tuning::C)));
+
+        assert_eq!(all.len(), 24);
+        assert_eq!(distinct.len(), 12);
+        assert!(distinct.iter().all(|v| v.unisons().is_empty()));
+    }
+
+    #[rstest(low_g, case(false), case(true))]
+    fn test_voicings_only_root_position(low_g: bool) {
+        let chord = Chord::from_str("C").unwrap();
+        let config = VoicingConfig {
+            low_g,
+            ..Default::default()
+        };
+        let bass_notes: Vec<Note> = chord
+            .voicings(config)
+            .filter_map(|v| v.bass_note())
+            .collect();
+        // Without the restriction, inversions with E or G in the bass are included.
+        assert!(bass_notes.contains(&Note::from_str("E").unwrap()));
+        assert!(bass_notes.contains(&Note::from_str("G").unwrap()));
+
+        let config = VoicingConfig {
+            only_root_position: true,
+            ..config
+        };
+        assert!(chord.voicings(config).count() > 0);
+        assert!(chord
+            .voicings(config)
+            .all(|v| v.bass_note() == Some(chord.root)));
+    }
+
+    #[rstest(
+        chord,
+        equivalent,
+        case("Caug", "Eaug"),
+        case("Caug", "G#aug"),
+        case("Dbaug", "Faug")
+    )]
+    fn test_voicings_augmented(chord: Chord, equivalent: Chord) {
+        let config = VoicingConfig::default();
+
+        // Chords with the same notes have the same voicings.
+        let voicings1: Vec<_> = chord.voicings(config).map(|v| v.fret_array()).collect();
+        let voicings2: Vec<_> = equivalent
+            .voicings(config)
+            .map(|v| v.fret_array())
+            .collect();
+        assert_eq!(voicings1, voicings2);
+
+        // The chord can be played in any position along the neck.
+        for position in 1..=9 {
+            let config = VoicingConfig::default().with_position(position);
+            assert!(chord.voicings(config).next().is_some());
+        }
+    }
+
+    #[rstest(
+        chord,
+        quality,
+        case("C", Quality::Major),
+        case("Am", Quality::Minor),
+        case("G7", Quality::Dominant),
+        case("Bdim", Quality::Diminished),
+        case("Eaug", Quality::Augmented),
+        case("Dsus2", Quality::Suspended),
+        case("F5", Quality::Power)
+    )]
+    fn test_quality(chord: Chord, quality: Quality) {
+        assert_eq!(chord.quality(), quality);
+    }
+
+    #[rstest(
+        chord,
+        simplified,
+        case("C", "C"),
+        case("C13", "C7"),
+        case("Cmaj9", "Cmaj7"),
+        case("C#m11", "C#m7"),
+        case("Dbadd9", "Db"),
+        case("F6/9", "F6"),
+        case("Bbm7b5", "Bbm7b5")
+    )]
+    fn test_simplify(chord: Chord, simplified: Chord) {
+        let chord = chord.simplify();
+        assert_eq!(chord, simplified);
+        assert_eq!(chord.to_string(), simplified.to_string());
+    }
+
+    #[rstest(
+        chord,
+        equivalents,
+        case("C", vec![]),
+        case("Cm7", vec![]),
+        case("Cdim7", vec!["D#dim7", "F#dim7", "Adim7"]),
+        case("Ebdim7", vec!["F#dim7", "Adim7", "Cdim7"]),
+        case("Caug", vec!["Eaug", "G#aug"]),
+        case("C7b5", vec!["F#7b5"]),
+    )]
+    fn test_equivalents(chord: Chord, equivalents: Vec<&str>) {
+        let names: Vec<String> = chord.equivalents().iter().map(|c| c.name()).collect();
+        assert_eq!(names, equivalents);
+    }
+
+    #[rstest(
+        chord,
+        position,
+        case("C", 3),
+        case("C", 5),
+        case("Am7", 2),
+        case("G7", 7),
+        case("F#m", 12)
+    )]
+    fn test_voicings_position(chord: Chord, position: FretID) {
+        let config = VoicingConfig::default().with_position(position);
+        let mut voicings = chord.voicings(config).peekable();
+
+        assert!(voicings.peek().is_some());
+
+        for voicing in voicings {
+            // Open strings are outside of the window.
+            assert!(voicing.frets().all(|f| f >= position && f <= position + 3));
+        }
+    }
+
+    #[rstest(
+        chord, min_fret, max_fret, max_span,
+        case("C", 0, 12, 4),
+        // `min_fret` exactly on a chord tone's fret.
+        case("C", 3, 12, 4),
+        case("C", 3, 5, 4),
+        case("C", 7, 10, 4),
+        case("G", 2, 5, 3),
+        // `max_fret` exactly on a chord tone's fret.
+        case("C", 0, 3, 4),
+        case("Am", 0, 2, 4),
+        case("F#m7", 9, 14, 4),
+        // Boundaries around the octave.
+        case("C7", 10, 15, 4),
+        case("Bb", 11, 21, 5),
+        case("D9", 12, 21, 5),
+    )]
+    fn test_voicings_fret_boundaries(
+        chord: Chord,
+        min_fret: FretID,
+        max_fret: FretID,
+        max_span: Semitones,
+    ) {
+        let config = VoicingConfig {
+            min_fret,
+            max_fret,
+            max_span,
+            ..Default::default()
+        };
+
+        // Check all fret combinations within the range by brute force.
+        let expected: Vec<Voicing> = (0..STRING_COUNT)
+            .map(|_| min_fret..=max_fret)
+            .multi_cartesian_product()
+            .map(|frets| Voicing::new([frets[0], frets[1], frets[2], frets[3]], Tuning::C))
+            .filter(|v| v.spells_out(&chord) && v.get_span() <= max_span)
+            .sorted()
+            .collect();
+
+        assert!(!expected.is_empty());
+        assert_eq!(chord.voicings(config).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest(
+        chords,
+        count,
+        case(vec!["C", "Cm"], 2),
+        case(vec!["C", "D"], 2),
+        case(vec!["C#", "Db"], 1),
+        case(vec!["C", "Cmaj", "CM"], 1),
+        case(vec!["C#m7", "Dbm7", "Dbmin7", "Cm7"], 2),
+    )]
+    fn test_hash(chords: Vec<&str>, count: usize) {
+        let set: HashSet<Chord> = chords.iter().map(|s| Chord::from_str(s).unwrap()).collect();
+        assert_eq!(set.len(), count);
+    }
 }
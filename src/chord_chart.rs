@@ -1,11 +1,135 @@
 use std::{cmp::max, fmt, fmt::Write};
 
-use crate::{FretID, Semitones, UkeString, Voicing, MIN_CHART_WIDTH};
+use clap::ValueEnum;
+
+use crate::{FretID, Note, Semitones, UkeString, Voicing, MIN_CHART_WIDTH, STRING_COUNT};
+
+/// Frets that are commonly marked with a single dot (inlay) on the fretboard.
+const SINGLE_INLAY_FRETS: [FretID; 6] = [5, 7, 10, 15, 17, 19];
+
+/// Frets that are commonly marked with a double dot (inlay) on the fretboard.
+const DOUBLE_INLAY_FRETS: [FretID; 1] = [12];
+
+/// Symbol marking the strings that play the highlighted note (see `ChordChart::with_highlight`).
+const HIGHLIGHT_MARKER: &str = "R";
+
+/// Number of spaces between chord charts printed side by side.
+const COLUMN_GAP: usize = 4;
+
+/// The lines of a chord chart, split up so that the corresponding lines
+/// of several charts can be aligned when printing them side by side.
+struct ChartLines {
+    /// The title, if any.
+    title: Option<String>,
+    /// The fret numbers, if requested.
+    fret_numbers: Option<String>,
+    /// The position markers, if requested and if any are shown.
+    inlays: Option<String>,
+    /// One line for each string.
+    strings: Vec<String>,
+    /// The number of the first fret shown, if the chart does not start at the nut.
+    base_fret: Option<String>,
+}
+
+impl ChartLines {
+    fn iter(&self) -> impl Iterator<Item = &String> {
+        self.title
+            .iter()
+            .chain(self.fret_numbers.iter())
+            .chain(self.inlays.iter())
+            .chain(self.strings.iter())
+            .chain(self.base_fret.iter())
+    }
+}
+
+/// Presets for the characters used to draw a chord chart
+/// (see `ChartStyle` for the individual characters).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum DiagramStyle {
+    /// Plain ASCII: `o` for open strings, `|` for frets and the
+    /// numbers of the fingers on the strings, e.g. `o||---|-2-|`.
+    #[default]
+    Dash,
+    /// Unicode dots: `○` for open strings, `+` for frets and `●` for
+    /// the positions to be pressed down, e.g. `○||---+-●-+`.
+    Dot,
+}
+
+/// The characters used to draw a chord chart. Start from one of the
+/// `DiagramStyle` presets and adjust single characters as needed, e.g.
+/// `ChartStyle { open: '0', ..ChartStyle::default() }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChartStyle {
+    /// Symbol in front of the nut marking an open string.
+    pub open: char,
+    /// Symbol in front of the nut marking a muted string.
+    pub muted: char,
+    /// Symbol marking a position pressed down if no finger numbers are shown.
+    pub fretted: char,
+    /// Symbol separating two frets.
+    pub fret: char,
+    /// Mark the positions pressed down with the numbers of the fingers
+    /// instead of `fretted`.
+    pub finger_numbers: bool,
+}
+
+impl ChartStyle {
+    /// Return the symbol marking the position pressed down by `finger`.
+    fn finger(self, finger: u8) -> String {
+        match self.finger_numbers {
+            true => finger.to_string(),
+            false => self.fretted.to_string(),
+        }
+    }
+}
+
+impl Default for ChartStyle {
+    fn default() -> Self {
+        DiagramStyle::default().into()
+    }
+}
+
+impl From<DiagramStyle> for ChartStyle {
+    fn from(style: DiagramStyle) -> Self {
+        match style {
+            DiagramStyle::Dash => Self {
+                open: 'o',
+                muted: 'x',
+                fretted: '*',
+                fret: '|',
+                finger_numbers: true,
+            },
+            DiagramStyle::Dot => Self {
+                open: '○',
+                muted: 'x',
+                fretted: '●',
+                fret: '+',
+                finger_numbers: false,
+            },
+        }
+    }
+}
 
 pub struct ChordChart {
     voicing: Voicing,
     /// Number of frets to use to display the chord voicing
     width: Semitones,
+    /// Show the numbers of the frets above the chart
+    fret_numbers: bool,
+    /// Mark the frets with position markers (inlays) above the chart
+    inlays: bool,
+    /// Show the root notes of the strings in front of the chart
+    string_labels: bool,
+    /// Show the notes played on the strings behind the chart
+    note_labels: bool,
+    /// Stack the strings the other way round, i.e. with the G string on top
+    reverse_strings: bool,
+    /// Characters used to draw the chart
+    style: ChartStyle,
+    /// Line printed above the chart, e.g. the name of the chord
+    title: Option<String>,
+    /// Note to be marked on all the strings playing it, e.g. the root of the chord
+    highlight: Option<Note>,
 }
 
 impl ChordChart {
@@ -14,7 +138,76 @@ impl ChordChart {
 
         assert!(voicing.get_span() <= width);
 
-        Self { voicing, width }
+        Self {
+            voicing,
+            width,
+            fret_numbers: false,
+            inlays: false,
+            string_labels: true,
+            note_labels: true,
+            reverse_strings: false,
+            style: ChartStyle::default(),
+            title: None,
+            highlight: None,
+        }
+    }
+
+    /// Set whether to print a header row showing the fret numbers.
+    pub fn with_fret_numbers(mut self, fret_numbers: bool) -> Self {
+        self.fret_numbers = fret_numbers;
+        self
+    }
+
+    /// Set whether to print a header row marking the frets that have position
+    /// markers (inlays) on a real fretboard, e.g. frets 5, 7, 10 and 12.
+    pub fn with_inlays(mut self, inlays: bool) -> Self {
+        self.inlays = inlays;
+        self
+    }
+
+    /// Set whether to print the root notes of the strings in front of the chart.
+    pub fn with_string_labels(mut self, string_labels: bool) -> Self {
+        self.string_labels = string_labels;
+        self
+    }
+
+    /// Set whether to print the notes played on the strings behind the chart.
+    pub fn with_note_labels(mut self, note_labels: bool) -> Self {
+        self.note_labels = note_labels;
+        self
+    }
+
+    /// Set whether to print the strings in reverse order, i.e. the G string
+    /// (or its counterpart) on top and the A string at the bottom.
+    pub fn with_reverse_strings(mut self, reverse_strings: bool) -> Self {
+        self.reverse_strings = reverse_strings;
+        self
+    }
+
+    /// Set the characters used to draw the chart to one of the presets.
+    pub fn with_diagram_style(mut self, style: DiagramStyle) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the characters used to draw the chart.
+    pub fn with_chart_style(mut self, style: ChartStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set a line to be printed above the chart, e.g. the name of the chord.
+    /// This is useful to tell apart several charts printed side by side.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Set a note to be marked on all the strings playing it (in any octave),
+    /// e.g. the root of the chord to see where it lies within a moveable shape.
+    pub fn with_highlight(mut self, note: Option<Note>) -> Self {
+        self.highlight = note;
+        self
     }
 
     /// Determine from which fret to show the fretboard.
@@ -41,51 +234,127 @@ impl ChordChart {
             .unwrap()
     }
 
+    /// Get the width of the column in front of the open string symbols,
+    /// i.e. the names of the strings followed by a space (if shown).
+    fn get_label_width(&self, root_width: usize) -> usize {
+        match self.string_labels {
+            true => root_width + 1,
+            false => 0,
+        }
+    }
+
+    /// Format a header line showing the numbers of the frets in the chord diagram.
+    /// Each number is aligned with the position of the finger on the fret.
+    pub fn format_fret_numbers(&self, base_fret: FretID, root_width: usize) -> String {
+        // Skip the root note, the open string symbol and the nut.
+        let prefix = " ".repeat(self.get_label_width(root_width) + 2);
+
+        let s: String = (base_fret..base_fret + self.width).fold(String::new(), |mut output, i| {
+            let _ = write!(output, "{i:>3} ");
+            output
+        });
+
+        format!("{prefix}{}\n", s.trim_end())
+    }
+
+    /// Format a header line marking the frets in the chord diagram that have
+    /// position markers on the fretboard, using `*` for a single dot and `**`
+    /// for a double dot. Return an empty string if no such fret is shown.
+    pub fn format_inlays(&self, base_fret: FretID, root_width: usize) -> String {
+        let frets = base_fret..base_fret + self.width;
+
+        if !frets
+            .clone()
+            .any(|i| SINGLE_INLAY_FRETS.contains(&i) || DOUBLE_INLAY_FRETS.contains(&i))
+        {
+            return "".to_string();
+        }
+
+        // Skip the root note, the open string symbol and the nut.
+        let prefix = " ".repeat(self.get_label_width(root_width) + 2);
+
+        let s: String = frets.fold(String::new(), |mut output, i| {
+            let marker = match i {
+                i if SINGLE_INLAY_FRETS.contains(&i) => "*",
+                i if DOUBLE_INLAY_FRETS.contains(&i) => "**",
+                _ => "",
+            };
+            let _ = write!(output, "{marker:>3} ");
+            output
+        });
+
+        format!("{prefix}{}\n", s.trim_end())
+    }
+
     /// Format a line that represents a ukulele string in a chord diagram.
+    /// Muted strings are marked with the style's muted symbol (`x` by default)
+    /// and have no fret pressed down and no note label. Strings playing the
+    /// highlighted note (if any) are marked with `R` at the end of the line.
     pub fn format_line(
         &self,
         uke_string: UkeString,
         base_fret: FretID,
         root_width: usize,
         finger: u8,
+        muted: bool,
     ) -> String {
         let (root, fret, note) = uke_string;
 
-        let root_str = format!("{:width$}", root.to_string(), width = root_width);
+        let root_str = match self.string_labels {
+            true => format!("{:width$} ", root.to_string(), width = root_width),
+            false => "".to_string(),
+        };
 
         // Show a symbol for the nut if the chord is played on the lower
         // end of the fretboard. Indicate ongoing strings otherwise.
-        let nut = match base_fret {
-            1 => "||",
-            _ => "-|",
+        // Open strings always ring from the nut, even if the section of
+        // the fretboard shown starts at a higher fret.
+        let fret_sym = self.style.fret;
+
+        let nut = match (base_fret, fret, muted) {
+            (1, _, _) | (_, 0, false) => "||".to_string(),
+            _ => format!("-{fret_sym}"),
         };
 
-        // Mark open strings with a special symbol.
-        let sym = match fret {
-            0 => "o",
-            _ => " ",
+        // Mark muted and open strings with a special symbol.
+        let sym = match (fret, muted) {
+            (_, true) => self.style.muted,
+            (0, false) => self.style.open,
+            _ => ' ',
         };
 
         // Create a line representing the string with the fret to be pressed.
         let s: String = (base_fret..base_fret + self.width)
             .map(|i| {
-                if fret == i {
-                    finger.to_string()
+                if fret == i && !muted {
+                    self.style.finger(finger)
                 } else {
                     "-".to_string()
                 }
             })
             .fold(String::new(), |mut output, c| {
-                let _ = write!(output, "-{c}-|");
+                let _ = write!(output, "-{c}-{fret_sym}");
                 output
             });
 
-        format!("{root_str} {sym}{nut}{s}- {note}\n")
+        let note_str = match (self.note_labels, muted) {
+            (true, false) => format!("- {note}"),
+            (true, true) => "-".to_string(),
+            (false, _) => "".to_string(),
+        };
+
+        let highlight_str = match self.highlight {
+            Some(highlight) if highlight == note && !muted => format!(" {HIGHLIGHT_MARKER}"),
+            _ => "".to_string(),
+        };
+
+        format!("{root_str}{sym}{nut}{s}{note_str}{highlight_str}\n")
     }
 }
 
-impl fmt::Display for ChordChart {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ChordChart {
+    /// Split the chart into its lines (without line breaks).
+    fn lines(&self) -> ChartLines {
         // Determine from which fret to show the fretboard.
         let base_fret = self.get_base_fret();
 
@@ -95,22 +364,102 @@ impl fmt::Display for ChordChart {
 
         let fingers_on_strings = self.voicing.fingers_on_strings();
 
+        let trim = |s: String| s.trim_end_matches('\n').to_string();
+
+        let fret_numbers = match self.fret_numbers {
+            true => Some(trim(self.format_fret_numbers(base_fret, root_width))),
+            false => None,
+        };
+
+        let inlays = match self.inlays {
+            true => Some(trim(self.format_inlays(base_fret, root_width))).filter(|s| !s.is_empty()),
+            false => None,
+        };
+
         // Create a diagram for each ukulele string.
-        let s: String = self
+        let mut strings: Vec<String> = self
             .voicing
             .uke_strings()
-            .rev()
-            .zip(fingers_on_strings.iter().rev())
-            .map(|(us, f)| self.format_line(*us, base_fret, root_width, *f))
+            .zip(fingers_on_strings.iter())
+            .zip(self.voicing.muted())
+            .map(|((us, f), m)| trim(self.format_line(*us, base_fret, root_width, *f, *m)))
             .collect();
 
+        // By default, show the highest string on top.
+        if !self.reverse_strings {
+            strings.reverse();
+        }
+
         // If the fretboard section shown does not include the nut,
         // indicate the number of the first fret shown.
-        if base_fret > 1 {
-            return writeln!(f, "{s}{base_fret:width$}", width = root_width + 6);
+        let base_fret = match base_fret {
+            1 => None,
+            _ => {
+                let width = self.get_label_width(root_width) + 5;
+                Some(format!("{base_fret:width$}"))
+            }
+        };
+
+        ChartLines {
+            title: self.title.clone(),
+            fret_numbers,
+            inlays,
+            strings,
+            base_fret,
         }
+    }
+
+    /// Format several chord charts next to each other. Corresponding lines
+    /// are aligned, e.g. the strings of a chart starting at the nut are on
+    /// the same lines as those of a chart with fret numbers above it.
+    pub fn side_by_side(charts: &[Self]) -> String {
+        let lines: Vec<ChartLines> = charts.iter().map(|c| c.lines()).collect();
+
+        // Pad the lines of each chart to the width of its longest line.
+        let widths: Vec<usize> = lines
+            .iter()
+            .map(|l| l.iter().map(|s| s.chars().count()).max().unwrap_or(0))
+            .collect();
+
+        let join = |cells: Vec<Option<&String>>| -> String {
+            let row: String = cells
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| {
+                    let cell = cell.map(|s| s.as_str()).unwrap_or("");
+                    let padding = width - cell.chars().count() + COLUMN_GAP;
+                    format!("{cell}{}", " ".repeat(padding))
+                })
+                .collect();
+            format!("{}\n", row.trim_end())
+        };
 
-        write!(f, "{s}")
+        let titles = lines.iter().map(|l| l.title.as_ref()).collect();
+        let fret_numbers = lines.iter().map(|l| l.fret_numbers.as_ref()).collect();
+        let inlays = lines.iter().map(|l| l.inlays.as_ref()).collect();
+        let strings = (0..STRING_COUNT).map(|i| lines.iter().map(|l| l.strings.get(i)).collect());
+        let base_fret = lines.iter().map(|l| l.base_fret.as_ref()).collect();
+
+        // Only print header and footer lines if at least one chart has them.
+        let rows: Vec<String> = vec![titles, fret_numbers, inlays]
+            .into_iter()
+            .chain(strings)
+            .chain(vec![base_fret])
+            .filter(|cells: &Vec<Option<&String>>| cells.iter().any(|c| c.is_some()))
+            .map(join)
+            .collect();
+
+        rows.concat()
+    }
+}
+
+impl fmt::Display for ChordChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines().iter() {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -120,7 +469,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::{Chord, Tuning, VoicingConfig};
+    use crate::{Chord, FretPattern, Tuning, VoicingConfig, STRING_COUNT};
 
     #[rstest(chord, tuning, diagram,
         case(
@@ -159,7 +508,7 @@ mod tests {
             indoc!("
                 A  ||---|---|---|-4-|- C#
                 E o||---|---|---|---|- E
-                C  ||-2-|---|---|---|- C#
+                C  ||---|---|---|-3-|- E
                 G  ||-1-|---|---|---|- G#
             ")
         ),
@@ -169,7 +518,7 @@ mod tests {
             indoc!("
                 A  ||---|---|---|-4-|- Db
                 E o||---|---|---|---|- E
-                C  ||-2-|---|---|---|- Db
+                C  ||---|---|---|-3-|- E
                 G  ||-1-|---|---|---|- Ab
             ")
         ),
@@ -203,4 +552,480 @@ mod tests {
         let chord_chart = ChordChart::new(voicing, 4);
         assert_eq!(chord_chart.to_string(), diagram);
     }
+
+    #[rstest(frets, width, diagram,
+        case(
+            [0, 0, 0, 7],
+            4,
+            indoc!("
+                A  -|-1-|---|---|---|- E
+                E o||---|---|---|---|- E
+                C o||---|---|---|---|- C
+                G o||---|---|---|---|- G
+                      7
+            "),
+        ),
+        case(
+            [3, 0, 0, 12],
+            10,
+            indoc!("
+                A  -|---|---|---|---|---|---|---|---|---|-4-|- A
+                E o||---|---|---|---|---|---|---|---|---|---|- E
+                C o||---|---|---|---|---|---|---|---|---|---|- C
+                G  -|-1-|---|---|---|---|---|---|---|---|---|- A#
+                      3
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_open_strings(
+        frets: [FretID; STRING_COUNT],
+        width: Semitones,
+        diagram: &str,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, width);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[rstest(frets, tuning, diagram,
+        case(
+            [0, 0, 0, 3],
+            Tuning::C,
+            indoc!("
+                      1   2   3   4
+                A  ||---|---|-3-|---|- C
+                E o||---|---|---|---|- E
+                C o||---|---|---|---|- C
+                G o||---|---|---|---|- G
+            "),
+        ),
+        case(
+            [5, 4, 3, 3],
+            Tuning::D,
+            indoc!("
+                       3   4   5   6
+                B   -|-1-|---|---|---|- D
+                F#  -|-1-|---|---|---|- A
+                D   -|---|-2-|---|---|- F#
+                A   -|---|---|-3-|---|- D
+                       3
+            "),
+        ),
+        case(
+            [9, 9, 10, 12],
+            Tuning::C,
+            indoc!("
+                      9  10  11  12
+                A  -|---|---|---|-4-|- A
+                E  -|---|-2-|---|---|- D
+                C  -|-1-|---|---|---|- A
+                G  -|-1-|---|---|---|- E
+                      9
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_fret_numbers(
+        frets: [FretID; STRING_COUNT],
+        tuning: Tuning,
+        diagram: &str,
+    ) {
+        let voicing = Voicing::new(frets, tuning);
+        let chord_chart = ChordChart::new(voicing, 4).with_fret_numbers(true);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[rstest(frets, string_labels, note_labels, diagram,
+        case(
+            [0, 0, 0, 3],
+            false,
+            true,
+            indoc!("
+                 ||---|---|-3-|---|- C
+                o||---|---|---|---|- E
+                o||---|---|---|---|- C
+                o||---|---|---|---|- G
+            "),
+        ),
+        case(
+            [0, 0, 0, 3],
+            true,
+            false,
+            indoc!("
+                A  ||---|---|-3-|---|
+                E o||---|---|---|---|
+                C o||---|---|---|---|
+                G o||---|---|---|---|
+            "),
+        ),
+        case(
+            [5, 4, 3, 3],
+            false,
+            false,
+            // Not using indoc here as all lines start with a space.
+            concat!(
+                " -|-1-|---|---|---|\n",
+                " -|-1-|---|---|---|\n",
+                " -|---|-2-|---|---|\n",
+                " -|---|---|-3-|---|\n",
+                "    3\n",
+            ),
+        ),
+    )]
+    fn test_to_diagram_without_labels(
+        frets: [FretID; STRING_COUNT],
+        string_labels: bool,
+        note_labels: bool,
+        diagram: &str,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4)
+            .with_string_labels(string_labels)
+            .with_note_labels(note_labels);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[rstest(
+        frets,
+        diagram,
+        case(
+            [0, 0, 0, 3],
+            indoc!("
+                G o||---|---|---|---|- G
+                C o||---|---|---|---|- C
+                E o||---|---|---|---|- E
+                A  ||---|---|-3-|---|- C
+            "),
+        ),
+        case(
+            [2, 2, 2, 0],
+            indoc!("
+                G  ||---|-1-|---|---|- A
+                C  ||---|-1-|---|---|- D
+                E  ||---|-1-|---|---|- F#
+                A o||---|---|---|---|- A
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_reverse_strings(frets: [FretID; STRING_COUNT], diagram: &str) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4).with_reverse_strings(true);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[rstest(frets, fret_numbers, diagram,
+        case(
+            [7, 7, 7, 5],
+            false,
+            indoc!("
+                      *       *
+                A  -|-1-|---|---|---|- D
+                E  -|---|---|-4-|---|- B
+                C  -|---|---|-3-|---|- G
+                G  -|---|---|-2-|---|- D
+                      5
+            "),
+        ),
+        case(
+            [9, 9, 10, 12],
+            true,
+            indoc!("
+                      9  10  11  12
+                          *      **
+                A  -|---|---|---|-4-|- A
+                E  -|---|-2-|---|---|- D
+                C  -|-1-|---|---|---|- A
+                G  -|-1-|---|---|---|- E
+                      9
+            "),
+        ),
+        case(
+            [0, 0, 0, 3],
+            false,
+            indoc!("
+                A  ||---|---|-3-|---|- C
+                E o||---|---|---|---|- E
+                C o||---|---|---|---|- C
+                G o||---|---|---|---|- G
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_inlays(
+        frets: [FretID; STRING_COUNT],
+        fret_numbers: bool,
+        diagram: &str,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4)
+            .with_fret_numbers(fret_numbers)
+            .with_inlays(true);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[rstest(
+        frets,
+        style,
+        diagram,
+        case(
+            [0, 0, 0, 3],
+            DiagramStyle::Dash,
+            indoc!("
+                A  ||---|---|-3-|---|- C
+                E o||---|---|---|---|- E
+                C o||---|---|---|---|- C
+                G o||---|---|---|---|- G
+            "),
+        ),
+        case(
+            [0, 0, 0, 3],
+            DiagramStyle::Dot,
+            indoc!("
+                A  ||---+---+-●-+---+- C
+                E ○||---+---+---+---+- E
+                C ○||---+---+---+---+- C
+                G ○||---+---+---+---+- G
+            "),
+        ),
+        case(
+            [7, 7, 7, 5],
+            DiagramStyle::Dot,
+            indoc!("
+                A  -+-●-+---+---+---+- D
+                E  -+---+---+-●-+---+- B
+                C  -+---+---+-●-+---+- G
+                G  -+---+---+-●-+---+- D
+                      5
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_style(
+        frets: [FretID; STRING_COUNT],
+        style: DiagramStyle,
+        diagram: &str,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4).with_diagram_style(style);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[test]
+    fn test_default_chart_style() {
+        let voicing = Voicing::new([0, 0, 0, 3], Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4);
+        let styled = ChordChart::new(voicing, 4).with_chart_style(ChartStyle::default());
+        assert_eq!(chord_chart.to_string(), styled.to_string());
+    }
+
+    #[rstest(
+        fret_pattern,
+        diagram,
+        case(
+            "x003",
+            indoc!("
+                A  ||---|---|-#-|---|- C
+                E 0||---|---|---|---|- E
+                C 0||---|---|---|---|- C
+                G X||---|---|---|---|-
+            "),
+        ),
+        case(
+            "2100",
+            indoc!("
+                A 0||---|---|---|---|- A
+                E 0||---|---|---|---|- E
+                C  ||-#-|---|---|---|- C#
+                G  ||---|-#-|---|---|- A
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_chart_style(fret_pattern: FretPattern, diagram: &str) {
+        let style = ChartStyle {
+            open: '0',
+            muted: 'X',
+            fretted: '#',
+            finger_numbers: false,
+            ..ChartStyle::default()
+        };
+        let voicing = Voicing::new(fret_pattern, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4).with_chart_style(style);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[rstest(fret_pattern, note_labels, diagram,
+        case(
+            "x003",
+            true,
+            indoc!("
+                A  ||---|---|-3-|---|- C
+                E o||---|---|---|---|- E
+                C o||---|---|---|---|- C
+                G x||---|---|---|---|-
+            "),
+        ),
+        case(
+            "7x87",
+            true,
+            indoc!("
+                A  -|-2-|---|---|---|- E
+                E  -|---|-3-|---|---|- C
+                C x-|---|---|---|---|-
+                G  -|-1-|---|---|---|- D
+                      7
+            "),
+        ),
+        case(
+            "x003",
+            false,
+            indoc!("
+                A  ||---|---|-3-|---|
+                E o||---|---|---|---|
+                C o||---|---|---|---|
+                G x||---|---|---|---|
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_muted_strings(
+        fret_pattern: FretPattern,
+        note_labels: bool,
+        diagram: &str,
+    ) {
+        let voicing = Voicing::new(fret_pattern, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4).with_note_labels(note_labels);
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[rstest(frets1, frets2, fret_numbers, diagram,
+        case(
+            [0, 0, 0, 3],
+            [5, 4, 3, 3],
+            false,
+            indoc!("
+                A  ||---|---|-3-|---|- C    A  -|-1-|---|---|---|- C
+                E o||---|---|---|---|- E    E  -|-1-|---|---|---|- G
+                C o||---|---|---|---|- C    C  -|---|-2-|---|---|- E
+                G o||---|---|---|---|- G    G  -|---|---|-3-|---|- C
+                                                  3
+            "),
+        ),
+        case(
+            [7, 7, 7, 5],
+            [2, 1, 0, 1],
+            true,
+            indoc!("
+                      5   6   7   8               1   2   3   4
+                A  -|-1-|---|---|---|- D    A  ||-2-|---|---|---|- A#
+                E  -|---|---|-4-|---|- B    E o||---|---|---|---|- E
+                C  -|---|---|-3-|---|- G    C  ||-1-|---|---|---|- C#
+                G  -|---|---|-2-|---|- D    G  ||---|-3-|---|---|- A
+                      5
+            "),
+        ),
+    )]
+    fn test_side_by_side(
+        frets1: [FretID; STRING_COUNT],
+        frets2: [FretID; STRING_COUNT],
+        fret_numbers: bool,
+        diagram: &str,
+    ) {
+        let charts: Vec<ChordChart> = [frets1, frets2]
+            .iter()
+            .map(|&frets| {
+                ChordChart::new(Voicing::new(frets, Tuning::C), 4).with_fret_numbers(fret_numbers)
+            })
+            .collect();
+        assert_eq!(ChordChart::side_by_side(&charts), diagram);
+    }
+
+    #[rstest(frets, case([0, 0, 0, 3]), case([5, 4, 3, 3]), case([9, 9, 10, 12]))]
+    fn test_side_by_side_single(frets: [FretID; STRING_COUNT]) {
+        let chart = ChordChart::new(Voicing::new(frets, Tuning::C), 4)
+            .with_fret_numbers(true)
+            .with_inlays(true);
+        let s = chart.to_string();
+        assert_eq!(ChordChart::side_by_side(&[chart]), s);
+    }
+
+    #[rstest(
+        fret_pattern, highlight, note_labels, diagram,
+        case(
+            "0003",
+            "C",
+            true,
+            indoc!("
+                A  ||---|---|-3-|---|- C R
+                E o||---|---|---|---|- E
+                C o||---|---|---|---|- C R
+                G o||---|---|---|---|- G
+            "),
+        ),
+        case(
+            "5433",
+            "C",
+            false,
+            indoc!("
+                A  -|-1-|---|---|---| R
+                E  -|-1-|---|---|---|
+                C  -|---|-2-|---|---|
+                G  -|---|---|-3-|---| R
+                      3
+            "),
+        ),
+        case(
+            "x003",
+            "G",
+            true,
+            indoc!("
+                A  ||---|---|-3-|---|- C
+                E o||---|---|---|---|- E
+                C o||---|---|---|---|- C
+                G x||---|---|---|---|-
+            "),
+        ),
+    )]
+    fn test_to_diagram_with_highlight(
+        fret_pattern: FretPattern,
+        highlight: &str,
+        note_labels: bool,
+        diagram: &str,
+    ) {
+        let voicing = Voicing::new(fret_pattern, Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4)
+            .with_note_labels(note_labels)
+            .with_highlight(Some(highlight.parse().unwrap()));
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[test]
+    fn test_to_diagram_with_title() {
+        let voicing = Voicing::new([0, 0, 0, 3], Tuning::C);
+        let chord_chart = ChordChart::new(voicing, 4).with_title("[C]");
+        let diagram = indoc!(
+            "
+            [C]
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+        "
+        );
+        assert_eq!(chord_chart.to_string(), diagram);
+    }
+
+    #[test]
+    fn test_side_by_side_with_titles() {
+        let charts = vec![
+            ChordChart::new(Voicing::new([0, 0, 0, 3], Tuning::C), 4).with_title("[C]"),
+            ChordChart::new(Voicing::new([7, 7, 7, 5], Tuning::C), 4),
+        ];
+        let diagram = indoc!(
+            "
+            [C]
+            A  ||---|---|-3-|---|- C    A  -|-1-|---|---|---|- D
+            E o||---|---|---|---|- E    E  -|---|---|-4-|---|- B
+            C o||---|---|---|---|- C    C  -|---|---|-3-|---|- G
+            G o||---|---|---|---|- G    G  -|---|---|-2-|---|- D
+                                              5
+        "
+        );
+        assert_eq!(ChordChart::side_by_side(&charts), diagram);
+    }
 }
@@ -0,0 +1,82 @@
+//! Minimal support for songs in the [ChordPro](https://www.chordpro.org) format,
+//! where chords are written in square brackets in front of the lyrics they are
+//! played on, e.g. `[C]Twinkle, twinkle, [F]little [C]star`.
+
+/// Return the chords written in square brackets in the ChordPro song `text`,
+/// each chord only once and in the order of their first occurrence.
+/// The chords are returned as they are written; they are not checked to be valid
+/// chord names. Comment lines (starting with `#`) and directives (in curly
+/// braces, e.g. `{title: ...}`) are skipped.
+///
+/// ```
+/// use ukebox::chord_pro::chord_names;
+///
+/// let song = "{title: Song}\n[C]Twinkle, twinkle, [F]little [C]star";
+/// assert_eq!(chord_names(song), vec!["C", "F"]);
+/// ```
+pub fn chord_names(text: &str) -> Vec<&str> {
+    let mut names: Vec<&str> = vec![];
+
+    for line in text.lines().map(|l| l.trim_start()) {
+        if line.starts_with('#') || line.starts_with('{') {
+            continue;
+        }
+
+        let mut rest = line;
+
+        while let Some(start) = rest.find('[') {
+            let len = match rest[start..].find(']') {
+                Some(len) => len,
+                None => break,
+            };
+
+            let name = rest[start + 1..start + len].trim();
+
+            if !name.is_empty() && !names.contains(&name) {
+                names.push(name);
+            }
+
+            rest = &rest[start + len + 1..];
+        }
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        text,
+        names,
+        case("", vec![]),
+        case("Just some lyrics", vec![]),
+        case("[C] [Am] [F] [G]", vec!["C", "Am", "F", "G"]),
+        case("[C]Twinkle, twinkle, [F]little [C]star", vec!["C", "F"]),
+        case("[ G7 ]Text", vec!["G7"]),
+        case("[]Text [C", vec![]),
+        case("{title: [C]}\n# [D]\n[E]", vec!["E"]),
+    )]
+    fn test_chord_names(text: &str, names: Vec<&str>) {
+        assert_eq!(chord_names(text), names);
+    }
+
+    #[test]
+    fn test_chord_names_song() {
+        let song = indoc!(
+            "
+            {title: Example}
+            {subtitle: A short song}
+
+            [C]This is the [Am]first line
+            [F]This is the [G]second line
+            [C]And [Am]this is the [F]last [G7]one [C]
+        "
+        );
+        assert_eq!(chord_names(song), vec!["C", "Am", "F", "G", "G7"]);
+    }
+}
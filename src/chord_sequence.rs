@@ -1,38 +1,149 @@
-use std::{slice::Iter, str::FromStr};
+use std::{iter::FromIterator, slice::Iter, str::FromStr};
 
 use crate::Chord;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// A sequence of chords, e.g. the chord progression of a song.
+///
+/// It can be parsed from a string of whitespace-separated chord names:
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use ukebox::ChordSequence;
+///
+/// let chord_seq = ChordSequence::from_str("C Am F G7").unwrap();
+/// assert_eq!(chord_seq.chords().count(), 4);
+/// ```
+///
+/// It can also be built from chords computed elsewhere:
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use ukebox::{Chord, ChordSequence};
+///
+/// let mut chord_seq: ChordSequence = ["C", "Am", "F"]
+///     .iter()
+///     .map(|s| Chord::from_str(s).unwrap())
+///     .collect();
+/// chord_seq.push(Chord::from_str("G7").unwrap());
+/// assert_eq!(chord_seq.len(), 4);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ChordSequence {
     chords: Vec<Chord>,
 }
 
 impl ChordSequence {
+    /// Return an iterator over the chords of the sequence.
     pub fn chords(&self) -> Iter<'_, Chord> {
         self.chords.iter()
     }
 
+    /// Append a chord to the end of the sequence.
+    pub fn push(&mut self, chord: Chord) {
+        self.chords.push(chord);
+    }
+
+    /// Return the number of chords in the sequence.
+    pub fn len(&self) -> usize {
+        self.chords.len()
+    }
+
+    /// Return `true` if the sequence contains no chords.
+    pub fn is_empty(&self) -> bool {
+        self.chords.is_empty()
+    }
+
+    /// Transpose all the chords of the sequence by the given number of semitones.
     pub fn transpose(&self, semitones: i8) -> Self {
         let chords = self.chords().map(|c| c.transpose(semitones)).collect();
         Self { chords }
     }
 }
 
+impl From<Vec<Chord>> for ChordSequence {
+    fn from(chords: Vec<Chord>) -> Self {
+        Self { chords }
+    }
+}
+
+impl FromIterator<Chord> for ChordSequence {
+    fn from_iter<I: IntoIterator<Item = Chord>>(iter: I) -> Self {
+        Self {
+            chords: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for ChordSequence {
+    type Item = Chord;
+    type IntoIter = std::vec::IntoIter<Chord>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chords.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ChordSequence {
+    type Item = &'a Chord;
+    type IntoIter = Iter<'a, Chord>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chords()
+    }
+}
+
+/// Custom error for strings that cannot be parsed into chord sequences.
 #[derive(Debug, thiserror::Error)]
-#[error("could not parse chord sequence")]
-pub struct ParseChordSequenceError;
+#[error("could not parse chord sequence: invalid token '{token}'")]
+pub struct ParseChordSequenceError {
+    token: String,
+}
+
+/// Parse a repeat marker such as `x2` into the number of times to play a bar.
+fn parse_repeat(token: &str) -> Option<usize> {
+    let n = token.strip_prefix(['x', 'X'])?;
+    n.parse().ok().filter(|&n| n > 0)
+}
 
 impl FromStr for ChordSequence {
     type Err = ParseChordSequenceError;
 
+    /// Parse a chord sequence such as "C Am F G".
+    ///
+    /// Bars may be separated by `|` as on lead sheets, e.g. "C | Am | F G | C".
+    /// Empty bars are ignored. A bar can be repeated by adding a repeat
+    /// marker at its end, e.g. "C G x2 | F" results in C G C G F.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let res: Result<Vec<_>, _> = s.split_whitespace().map(Chord::from_str).collect();
+        let mut chords = vec![];
+
+        for bar in s.split('|') {
+            let mut tokens: Vec<&str> = bar.split_whitespace().collect();
 
-        if let Ok(chords) = res {
-            return Ok(Self { chords });
+            let repeat = match tokens.last().and_then(|t| parse_repeat(t)) {
+                Some(n) => {
+                    tokens.pop();
+                    n
+                }
+                None => 1,
+            };
+
+            let bar_chords = tokens
+                .iter()
+                .map(|t| {
+                    Chord::from_str(t).map_err(|_| ParseChordSequenceError {
+                        token: t.to_string(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for _ in 0..repeat {
+                chords.extend(bar_chords.iter().cloned());
+            }
         }
 
-        Err(ParseChordSequenceError)
+        Ok(Self { chords })
     }
 }
 
@@ -56,9 +167,74 @@ mod tests {
         assert_eq!(chords1, chords2);
     }
 
-    #[rstest(chord_seq, case("Z"), case("A Z"))]
-    fn test_from_str_fail(chord_seq: &str) {
-        assert!(ChordSequence::from_str(chord_seq).is_err());
+    #[rstest(
+        chord_seq,
+        chords,
+        case("C | Am | F G | C", &["C", "Am", "F", "G", "C"]),
+        case("| C | Am |", &["C", "Am"]),
+        case("C  |   | Am", &["C", "Am"]),
+        case("||", &[]),
+        case("C G x2", &["C", "G", "C", "G"]),
+        case("C x3 | F", &["C", "C", "C", "F"]),
+        case("C | Am F X2 | G", &["C", "Am", "F", "Am", "F", "G"]),
+        case("x2", &[]),
+    )]
+    fn test_from_str_bars(chord_seq: ChordSequence, chords: &[&str]) {
+        let chords1: Vec<Chord> = chord_seq.chords().cloned().collect();
+        let chords2: Vec<Chord> = chords.iter().map(|c| Chord::from_str(c).unwrap()).collect();
+        assert_eq!(chords1, chords2);
+    }
+
+    #[rstest(
+        chord_seq,
+        token,
+        case("Z", "Z"),
+        case("A Z", "Z"),
+        case("C | Am | Hm", "Hm"),
+        case("C x2 G", "x2"),
+        case("C x0", "x0"),
+        case("C ||| x", "x")
+    )]
+    fn test_from_str_fail(chord_seq: &str, token: &str) {
+        let err = ChordSequence::from_str(chord_seq).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("could not parse chord sequence: invalid token '{token}'")
+        );
+    }
+
+    #[rstest(
+        chords,
+        case(&[]),
+        case(&["C"]),
+        case(&["C", "Am", "F", "G7"]),
+    )]
+    fn test_from_iter(chords: &[&str]) {
+        let chords: Vec<Chord> = chords.iter().map(|c| Chord::from_str(c).unwrap()).collect();
+
+        let chord_seq: ChordSequence = chords.iter().cloned().collect();
+        assert_eq!(chord_seq.len(), chords.len());
+        assert_eq!(chord_seq.is_empty(), chords.is_empty());
+        assert_eq!(chord_seq, ChordSequence::from(chords.clone()));
+
+        // Iterate by reference and by value.
+        let chords1: Vec<Chord> = (&chord_seq).into_iter().cloned().collect();
+        assert_eq!(chords1, chords);
+        let chords2: Vec<Chord> = chord_seq.into_iter().collect();
+        assert_eq!(chords2, chords);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut chord_seq = ChordSequence::default();
+        assert!(chord_seq.is_empty());
+
+        for chord in ["C", "Am", "F", "G"] {
+            chord_seq.push(Chord::from_str(chord).unwrap());
+        }
+
+        assert_eq!(chord_seq.len(), 4);
+        assert_eq!(chord_seq, ChordSequence::from_str("C Am F G").unwrap());
     }
 
     #[rstest(
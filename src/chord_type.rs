@@ -1,6 +1,13 @@
-use std::{cmp::min, convert::TryFrom, fmt, str::FromStr};
+use std::{
+    cmp::{min, Ordering},
+    convert::TryFrom,
+    fmt,
+    str::FromStr,
+};
 
-use crate::{Interval, PitchClass, PITCH_CLASS_COUNT, STRING_COUNT};
+use itertools::Itertools;
+
+use crate::{Interval, PitchClass, Quality, Semitones, PITCH_CLASS_COUNT, STRING_COUNT};
 
 /// The type of the chord depending on the intervals it contains.
 ///
@@ -14,7 +21,11 @@ use crate::{Interval, PitchClass, PITCH_CLASS_COUNT, STRING_COUNT};
 /// * <https://chords.gock.net>
 /// * <https://ukulele-chords.com>
 /// * <https://ukulelehelper.com>
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Chord types are ordered by musical family rather than by declaration order
+/// (see `rank`), so that chords with several possible names are listed in
+/// a predictable order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChordType {
     Major,
     MajorSeventh,
@@ -29,6 +40,9 @@ pub enum ChordType {
     /// chord without the third. But as this overlaps with the dominant seventh suspended
     /// fourth chord and other sources list the dominant eleventh including the third,
     /// let's also keep it around here. (See https://en.wikipedia.org/wiki/Eleventh_chord)
+    /// When detecting chords, notes without a third are only considered a dominant eleventh
+    /// chord if they contain the ninth, otherwise they form a dominant seventh suspended
+    /// fourth chord.
     DominantEleventh,
     DominantThirteenth,
     DominantSeventhFlatNinth,
@@ -49,16 +63,25 @@ pub enum ChordType {
     Diminished,
     DiminishedSeventh,
     HalfDiminishedSeventh,
+    /// The power chord consists of only the root and the fifth. As every string
+    /// of the ukulele is played, its voicings double the root and/or the fifth
+    /// (e.g. G C G C for C5), so there is no need for a separate chord type
+    /// including the octave.
     Fifth,
     Augmented,
     AugmentedSeventh,
     AugmentedMajorSeventh,
     AddedNinth,
     AddedFourth,
+    /// A chord that is defined by an explicit list of intervals instead of
+    /// one of the chord types above (see `Chord::from_intervals`). Its intervals
+    /// are stored in the chord, so the chord type itself has none.
+    Custom,
 }
 
 impl ChordType {
-    /// Iterator over the values of the ChordType enum.
+    /// Iterator over the values of the ChordType enum (except `Custom`,
+    /// which cannot be named or detected).
     ///
     /// Unfortunately, we have to list them all and make sure to update
     /// this list if a value is added or removed.
@@ -106,6 +129,57 @@ impl ChordType {
         .copied()
     }
 
+    /// Return the position of the chord type in the ordering of chord types:
+    /// power chords and triads first, then chords with an added note, seventh
+    /// chords and finally extended chords. Within each group, the major family
+    /// comes first, followed by dominant, minor, diminished, augmented and
+    /// suspended chords.
+    fn rank(self) -> u8 {
+        use ChordType::*;
+
+        match self {
+            // Power chords and triads.
+            Fifth => 0,
+            Major => 1,
+            Minor => 2,
+            Diminished => 3,
+            Augmented => 4,
+            SuspendedFourth => 5,
+            SuspendedSecond => 6,
+            // Triads with an added note.
+            MajorSixth => 10,
+            AddedNinth => 11,
+            AddedFourth => 12,
+            SixthNinth => 13,
+            MinorSixth => 14,
+            // Seventh chords.
+            MajorSeventh => 20,
+            DominantSeventh => 21,
+            DominantSeventhFlatFifth => 22,
+            MinorSeventh => 23,
+            MinorMajorSeventh => 24,
+            HalfDiminishedSeventh => 25,
+            DiminishedSeventh => 26,
+            AugmentedSeventh => 27,
+            AugmentedMajorSeventh => 28,
+            DominantSeventhSuspendedFourth => 29,
+            DominantSeventhSuspendedSecond => 30,
+            // Extended chords.
+            MajorNinth => 40,
+            DominantNinth => 41,
+            DominantSeventhFlatNinth => 42,
+            DominantSeventhSharpNinth => 43,
+            MinorNinth => 44,
+            MajorEleventh => 45,
+            DominantEleventh => 46,
+            MinorEleventh => 47,
+            MajorThirteenth => 48,
+            DominantThirteenth => 49,
+            MinorThirteenth => 50,
+            Custom => u8::MAX,
+        }
+    }
+
     /// Return an iterator over the chord type's intervals.
     pub fn intervals(&self) -> impl Iterator<Item = Interval> + '_ {
         use ChordType::*;
@@ -146,6 +220,7 @@ impl ChordType {
             AugmentedMajorSeventh => vec!["P1", "M3", "A5", "M7"],
             AddedNinth => vec!["P1", "M3", "P5", "M9"],
             AddedFourth => vec!["P1", "M3", "P4", "P5"],
+            Custom => vec![],
         };
 
         interval_names
@@ -192,13 +267,18 @@ impl ChordType {
             .filter(move |&i1| self.optional_intervals().all(|i2| i2 != i1))
     }
 
+    /// Return the chord type's interval formula, e.g. `P1 M3 P5` for a major chord.
+    pub fn formula(&self) -> String {
+        self.intervals().join(" ")
+    }
+
     /// Return an iterator over the symbols that can be used to denote a chord type.
     pub fn symbols(self) -> impl Iterator<Item = &'static str> + 'static {
         use ChordType::*;
 
         let symbols = match self {
-            Major => vec!["", "maj", "M"],
-            MajorSeventh => vec!["maj7", "M7"],
+            Major => vec!["", "maj", "M", "major"],
+            MajorSeventh => vec!["maj7", "M7", "major7"],
             MajorNinth => vec!["maj9", "M9"],
             MajorEleventh => vec!["maj11", "M11"],
             MajorThirteenth => vec!["maj13", "M13"],
@@ -232,13 +312,83 @@ impl ChordType {
             AugmentedMajorSeventh => vec!["augMaj7", "+M7"],
             AddedNinth => vec!["add9", "add2"],
             AddedFourth => vec!["add4"],
+            Custom => vec![],
         };
 
         symbols.into_iter()
     }
 
+    /// Return the coarse quality of the chord type, determined by its
+    /// third, fifth and seventh.
+    pub fn quality(&self) -> Quality {
+        Quality::from_intervals(&self.intervals().collect::<Vec<_>>())
+    }
+
+    /// Return `true` if the chord type is symmetric, i.e. if transposing
+    /// a chord of this type by less than an octave can result in a chord with
+    /// the same notes. For example, Cdim7, D#dim7, F#dim7 and Adim7 all consist
+    /// of the same four notes.
+    pub fn is_symmetric(&self) -> bool {
+        if let ChordType::Custom = self {
+            return false;
+        }
+
+        let semitones: Vec<Semitones> = self
+            .intervals()
+            .map(|i| i.to_semitones() % PITCH_CLASS_COUNT)
+            .sorted()
+            .collect();
+
+        (1..PITCH_CLASS_COUNT).any(|n| {
+            semitones
+                .iter()
+                .map(|s| (s + n) % PITCH_CLASS_COUNT)
+                .sorted()
+                .eq(semitones.iter().copied())
+        })
+    }
+
+    /// Return the essential chord type that an extended chord type can be
+    /// reduced to if it is too hard (or impossible) to play in full.
+    ///
+    /// Ninths, elevenths and thirteenths are reduced to the corresponding
+    /// seventh chords, added-tone chords to the underlying triads.
+    /// Chord types that are already essential are returned unchanged.
+    pub fn simplify(&self) -> Self {
+        use ChordType::*;
+
+        match self {
+            MajorNinth | MajorEleventh | MajorThirteenth => MajorSeventh,
+            SixthNinth => MajorSixth,
+            DominantNinth
+            | DominantEleventh
+            | DominantThirteenth
+            | DominantSeventhFlatNinth
+            | DominantSeventhSharpNinth => DominantSeventh,
+            MinorNinth | MinorEleventh | MinorThirteenth => MinorSeventh,
+            AddedNinth | AddedFourth => Major,
+            _ => *self,
+        }
+    }
+
+    /// Return the main symbol used to denote the chord type. It only
+    /// consists of ASCII characters. Custom chord types have no symbol.
     pub fn to_symbol(self) -> String {
-        self.symbols().next().unwrap().to_string()
+        self.symbols().next().unwrap_or_default().to_string()
+    }
+
+    /// Return the known chord type symbol that is closest to `s` (in terms of
+    /// edit distance), e.g. `min7` for the misspelled `min7b`. Return `None`
+    /// if no symbol is similar enough to be a plausible suggestion.
+    pub fn closest_symbol(s: &str) -> Option<&'static str> {
+        let s = normalize_symbol(s);
+
+        Self::values()
+            .flat_map(|ct| ct.symbols())
+            .map(|sym| (edit_distance(&normalize_symbol(sym), &s), sym))
+            .filter(|&(dist, _sym)| dist <= MAX_SUGGESTION_DISTANCE && dist < s.chars().count())
+            .min_by_key(|&(dist, _sym)| dist)
+            .map(|(_dist, sym)| sym)
     }
 }
 
@@ -282,40 +432,120 @@ impl fmt::Display for ChordType {
             AugmentedMajorSeventh => "augmented major 7th",
             AddedNinth => "added 9th",
             AddedFourth => "added 4th",
+            Custom => "custom",
         };
 
         write!(f, "{s}")
     }
 }
 
-#[derive(Debug)]
+impl PartialOrd for ChordType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ChordType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+/// Custom error for strings that are not a known chord type symbol.
+#[derive(Debug, thiserror::Error)]
+#[error("unknown chord type symbol")]
 pub struct NoValidChordTypeError;
 
+/// Parts of chord symbols that may be written in any case, e.g. `DIM7` or `Sus4`.
+/// All other characters are case-sensitive as they carry meaning, e.g. `m` stands
+/// for minor but `M` stands for major.
+/// Longer tokens come first so that e.g. `MAJOR` is not only lowercased up to `maj`.
+const CASE_INSENSITIVE_TOKENS: [&str; 6] = ["dim", "aug", "sus", "major", "maj", "add"];
+
+/// Bring the case-insensitive tokens of a chord symbol into lowercase,
+/// e.g. `mmaj7` for both `mMaj7` and `mMAJ7`.
+fn normalize_symbol(s: &str) -> String {
+    let mut normalized = String::new();
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        let token = CASE_INSENSITIVE_TOKENS.iter().find(|token| {
+            rest.get(..token.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(token))
+        });
+
+        let len = match token {
+            Some(token) => {
+                normalized.push_str(token);
+                token.len()
+            }
+            None => {
+                normalized.push(c);
+                c.len_utf8()
+            }
+        };
+
+        rest = &rest[len..];
+    }
+
+    normalized
+}
+
+/// Maximal edit distance between a misspelled chord type symbol
+/// and a known symbol for the latter to be suggested as a correction.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Compute the Levenshtein distance between `a` and `b`, i.e. the minimal number
+/// of characters to be inserted, deleted or substituted to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = cost.min(row[j] + 1).min(prev + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
 impl FromStr for ChordType {
     type Err = NoValidChordTypeError;
 
+    /// Find the chord type denoted by the symbol `s`. If there is no exact match,
+    /// the tokens in `CASE_INSENSITIVE_TOKENS` are compared regardless of case.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(chord_type) = Self::values().find(|ct| ct.symbols().any(|sym| sym == s)) {
+            return Ok(chord_type);
+        }
+
+        let s = normalize_symbol(s);
+
         Self::values()
-            .find(|ct| ct.symbols().any(|sym| sym == s))
+            .find(|ct| ct.symbols().any(|sym| normalize_symbol(sym) == s))
             .ok_or(NoValidChordTypeError)
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("no matching chord type found")]
-pub struct NoMatchingChordTypeFoundError;
-
-impl TryFrom<&[PitchClass]> for ChordType {
-    type Error = NoMatchingChordTypeFoundError;
-
-    /// Determine the chord type from a list of pitch classes representing a chord.
-    fn try_from(pitches: &[PitchClass]) -> Result<Self, Self::Error> {
-        // Subtract the root note's pitch class from all pitch classes to get the
-        // difference in semitones.
-        let mut pitch_diffs: Vec<_> = pitches.iter().map(|pc| *pc - pitches[0]).collect();
-
-        pitch_diffs.sort_unstable();
+/// Custom error for lists of pitch classes that cannot be identified as a chord.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ChordDetectionError {
+    #[error("not enough notes to determine a chord type")]
+    NotEnoughNotes,
+    #[error("no matching chord type found")]
+    NoMatchingType,
+}
 
+impl ChordType {
+    /// Find the first chord type whose intervals match the given differences
+    /// in semitones from the root note (sorted and including the root's 0).
+    fn from_semitones(pitch_diffs: &[Semitones]) -> Option<Self> {
         let to_semitones = |i: Interval| {
             let s = i.to_semitones();
             if s >= PITCH_CLASS_COUNT {
@@ -349,11 +579,56 @@ impl TryFrom<&[PitchClass]> for ChordType {
             let mut opt = pitch_diffs.iter().filter(|s| !req_sems.contains(s));
 
             if opt.all(|s| opt_sems.contains(s)) {
-                return Ok(chord_type);
+                return Some(chord_type);
             }
         }
 
-        Err(NoMatchingChordTypeFoundError)
+        None
+    }
+}
+
+impl TryFrom<&[PitchClass]> for ChordType {
+    type Error = ChordDetectionError;
+
+    /// Determine the chord type from a list of pitch classes representing a chord.
+    fn try_from(pitches: &[PitchClass]) -> Result<Self, Self::Error> {
+        // Even the smallest chord types consist of at least two notes.
+        let min_note_count = Self::values()
+            .map(|ct| min(ct.intervals().count(), STRING_COUNT))
+            .min()
+            .unwrap();
+
+        if pitches.len() < min_note_count {
+            return Err(ChordDetectionError::NotEnoughNotes);
+        }
+
+        // Subtract the root note's pitch class from all pitch classes to get the
+        // difference in semitones.
+        let mut pitch_diffs: Vec<_> = pitches.iter().map(|pc| *pc - pitches[0]).collect();
+
+        pitch_diffs.sort_unstable();
+
+        if let Some(chord_type) = Self::from_semitones(&pitch_diffs) {
+            return Ok(chord_type);
+        }
+
+        // Without a third, the notes of a dominant eleventh chord are ambiguous: If the ninth
+        // is missing as well, they make up a dominant seventh suspended fourth chord (which is
+        // found above), otherwise they are detected as a dominant eleventh chord played
+        // without its third (which `Chord` names accordingly, e.g. C11(no3)).
+        let has_third = pitch_diffs.iter().any(|s| matches!(s, 3 | 4));
+
+        if !has_third && pitch_diffs.contains(&2) {
+            let mut with_third = pitch_diffs.clone();
+            with_third.push(4);
+            with_third.sort_unstable();
+
+            if Self::from_semitones(&with_third) == Some(Self::DominantEleventh) {
+                return Ok(Self::DominantEleventh);
+            }
+        }
+
+        Err(ChordDetectionError::NoMatchingType)
     }
 }
 
@@ -429,14 +704,152 @@ mod tests {
         assert_eq!(ChordType::try_from(&pitches[..]).unwrap(), chord_type);
     }
 
+    #[rstest(
+        semitones, chord_type,
+        case(vec![0, 5, 7, 10], DominantSeventhSuspendedFourth),
+        case(vec![0, 5, 7, 10, 2], DominantEleventh),
+        case(vec![0, 5, 10, 2], DominantEleventh),
+        case(vec![0, 4, 7, 10, 5], DominantEleventh),
+        case(vec![0, 4, 7, 10, 2, 5], DominantEleventh),
+    )]
+    fn test_get_chord_type_eleventh_vs_sus(semitones: Vec<Semitones>, chord_type: ChordType) {
+        let pitches: Vec<PitchClass> = semitones.iter().map(|&s| C + s).collect();
+        assert_eq!(ChordType::try_from(&pitches[..]).unwrap(), chord_type);
+    }
+
+    #[rstest(
+        symbol,
+        chord_type,
+        case("maj7", MajorSeventh),
+        case("MAJ7", MajorSeventh),
+        case("Maj", Major),
+        case("M7", MajorSeventh),
+        case("m7", MinorSeventh),
+        case("DIM7", DiminishedSeventh),
+        case("Dim", Diminished),
+        case("Sus4", SuspendedFourth),
+        case("7SUS2", DominantSeventhSuspendedSecond),
+        case("AUG", Augmented),
+        case("Add9", AddedNinth),
+        case("mMaj7", MinorMajorSeventh),
+        case("mMAJ7", MinorMajorSeventh),
+        case("mmaj7", MinorMajorSeventh),
+        case("mM7", MinorMajorSeventh),
+        case("AugMaj7", AugmentedMajorSeventh)
+    )]
+    fn test_from_str(symbol: &str, chord_type: ChordType) {
+        assert_eq!(ChordType::from_str(symbol).unwrap(), chord_type);
+    }
+
+    #[rstest(
+        symbol,
+        chord_type,
+        // All the spellings of major ...
+        case("", Major),
+        case("M", Major),
+        case("maj", Major),
+        case("Maj", Major),
+        case("MAJ", Major),
+        case("major", Major),
+        case("Major", Major),
+        case("MAJOR", Major),
+        // ... and of major 7th.
+        case("M7", MajorSeventh),
+        case("maj7", MajorSeventh),
+        case("Maj7", MajorSeventh),
+        case("MAJ7", MajorSeventh),
+        case("major7", MajorSeventh),
+        case("Major7", MajorSeventh),
+        // Minor is not affected.
+        case("m", Minor),
+        case("min", Minor),
+        case("m7", MinorSeventh),
+        case("mMaj7", MinorMajorSeventh)
+    )]
+    fn test_from_str_major(symbol: &str, chord_type: ChordType) {
+        assert_eq!(ChordType::from_str(symbol).unwrap(), chord_type);
+    }
+
+    #[rstest(
+        symbol,
+        case("MM"),
+        case("mm"),
+        case("majo"),
+        case("majors"),
+        case("major9"),
+        case("Mmaj7"),
+        case("mmajor7"),
+        case("maj 7"),
+        case(" maj7")
+    )]
+    fn test_from_str_major_error(symbol: &str) {
+        assert!(ChordType::from_str(symbol).is_err());
+    }
+
+    #[rstest(symbol, case("MM7"), case("MMaj7"), case("DOM7"), case("xyz"))]
+    fn test_from_str_error(symbol: &str) {
+        assert!(ChordType::from_str(symbol).is_err());
+    }
+
+    #[rstest(
+        a,
+        b,
+        dist,
+        case("", "", 0),
+        case("min7", "min7", 0),
+        case("min7b", "min7", 1),
+        case("min7", "m7", 2),
+        case("sus", "7sus4", 2),
+        case("maj7", "m7b5", 3),
+        case("", "dim", 3)
+    )]
+    fn test_edit_distance(a: &str, b: &str, dist: usize) {
+        assert_eq!(edit_distance(a, b), dist);
+        assert_eq!(edit_distance(b, a), dist);
+    }
+
+    #[rstest(
+        symbol,
+        suggestion,
+        case("min7b", Some("min7")),
+        case("mj7", Some("maj7")),
+        case("SUS5", Some("sus4")),
+        case("dimm7", Some("dim7")),
+        case("xyz", None),
+        case("q", None)
+    )]
+    fn test_closest_symbol(symbol: &str, suggestion: Option<&str>) {
+        assert_eq!(ChordType::closest_symbol(symbol), suggestion);
+    }
+
+    #[test]
+    fn test_normalized_symbols_are_unique() {
+        let symbols: Vec<String> = ChordType::values()
+            .flat_map(|ct| ct.symbols())
+            .map(normalize_symbol)
+            .collect();
+
+        assert_eq!(symbols.iter().unique().count(), symbols.len());
+    }
+
     #[rstest(
         pitches,
         case(vec![C, E]),
-        case(vec![D]),
         case(vec![C, F, ASharp]), // missing fifth
     )]
     fn test_get_chord_type_error(pitches: Vec<PitchClass>) {
-        assert!(ChordType::try_from(&pitches[..]).is_err());
+        assert_eq!(
+            ChordType::try_from(&pitches[..]),
+            Err(ChordDetectionError::NoMatchingType)
+        );
+    }
+
+    #[rstest(pitches, case(vec![]), case(vec![C]))]
+    fn test_get_chord_type_not_enough_notes(pitches: Vec<PitchClass>) {
+        assert_eq!(
+            ChordType::try_from(&pitches[..]),
+            Err(ChordDetectionError::NotEnoughNotes)
+        );
     }
 
     #[rstest(
@@ -454,4 +867,128 @@ mod tests {
 
         assert_eq!(req_ints, exp_ints);
     }
+
+    #[rstest(
+        chord_type,
+        formula,
+        case(Major, "P1 M3 P5"),
+        case(Minor, "P1 m3 P5"),
+        case(DominantSeventh, "P1 M3 P5 m7"),
+        case(DominantThirteenth, "P1 M3 P5 m7 M9 P11 M13")
+    )]
+    fn test_formula(chord_type: ChordType, formula: &str) {
+        assert_eq!(chord_type.formula(), formula);
+    }
+
+    #[rstest(
+        chord_type,
+        quality,
+        case(Major, Quality::Major),
+        case(MajorSeventh, Quality::Major),
+        case(AddedFourth, Quality::Major),
+        case(Minor, Quality::Minor),
+        case(MinorMajorSeventh, Quality::Minor),
+        case(DominantSeventh, Quality::Dominant),
+        case(DominantThirteenth, Quality::Dominant),
+        case(DominantSeventhFlatFifth, Quality::Dominant),
+        case(Diminished, Quality::Diminished),
+        case(HalfDiminishedSeventh, Quality::Diminished),
+        case(Augmented, Quality::Augmented),
+        case(AugmentedSeventh, Quality::Augmented),
+        case(SuspendedFourth, Quality::Suspended),
+        case(DominantSeventhSuspendedSecond, Quality::Suspended),
+        case(Fifth, Quality::Power)
+    )]
+    fn test_quality(chord_type: ChordType, quality: Quality) {
+        assert_eq!(chord_type.quality(), quality);
+    }
+
+    #[rstest(
+        chord_type,
+        simplified,
+        case(Major, Major),
+        case(MajorSeventh, MajorSeventh),
+        case(MajorNinth, MajorSeventh),
+        case(MajorEleventh, MajorSeventh),
+        case(MajorThirteenth, MajorSeventh),
+        case(MajorSixth, MajorSixth),
+        case(SixthNinth, MajorSixth),
+        case(DominantSeventh, DominantSeventh),
+        case(DominantNinth, DominantSeventh),
+        case(DominantEleventh, DominantSeventh),
+        case(DominantThirteenth, DominantSeventh),
+        case(DominantSeventhFlatNinth, DominantSeventh),
+        case(DominantSeventhSharpNinth, DominantSeventh),
+        case(DominantSeventhFlatFifth, DominantSeventhFlatFifth),
+        case(SuspendedFourth, SuspendedFourth),
+        case(SuspendedSecond, SuspendedSecond),
+        case(DominantSeventhSuspendedFourth, DominantSeventhSuspendedFourth),
+        case(DominantSeventhSuspendedSecond, DominantSeventhSuspendedSecond),
+        case(Minor, Minor),
+        case(MinorSeventh, MinorSeventh),
+        case(MinorMajorSeventh, MinorMajorSeventh),
+        case(MinorSixth, MinorSixth),
+        case(MinorNinth, MinorSeventh),
+        case(MinorEleventh, MinorSeventh),
+        case(MinorThirteenth, MinorSeventh),
+        case(Diminished, Diminished),
+        case(DiminishedSeventh, DiminishedSeventh),
+        case(HalfDiminishedSeventh, HalfDiminishedSeventh),
+        case(Fifth, Fifth),
+        case(Augmented, Augmented),
+        case(AugmentedSeventh, AugmentedSeventh),
+        case(AugmentedMajorSeventh, AugmentedMajorSeventh),
+        case(AddedNinth, Major),
+        case(AddedFourth, Major)
+    )]
+    fn test_simplify(chord_type: ChordType, simplified: ChordType) {
+        assert_eq!(chord_type.simplify(), simplified);
+        // Simplified chord types can't be simplified any further.
+        assert_eq!(simplified.simplify(), simplified);
+        // Simplifying keeps the quality of the chord.
+        assert_eq!(chord_type.quality(), simplified.quality());
+    }
+
+    #[test]
+    fn test_to_symbol_is_ascii() {
+        for chord_type in ChordType::values() {
+            assert!(chord_type.to_symbol().is_ascii());
+        }
+    }
+
+    #[rstest(
+        chord_type,
+        symmetric,
+        case(Major, false),
+        case(Minor, false),
+        case(DominantSeventh, false),
+        case(DominantSeventhFlatFifth, true),
+        case(Diminished, false),
+        case(DiminishedSeventh, true),
+        case(HalfDiminishedSeventh, false),
+        case(Augmented, true),
+        case(AugmentedSeventh, false),
+        case(Fifth, false)
+    )]
+    fn test_is_symmetric(chord_type: ChordType, symmetric: bool) {
+        assert_eq!(chord_type.is_symmetric(), symmetric);
+    }
+
+    #[rstest(
+        smaller,
+        larger,
+        case(Fifth, Major),
+        case(Major, Minor),
+        case(Minor, SuspendedFourth),
+        case(SuspendedFourth, SuspendedSecond),
+        case(MajorSixth, AddedNinth),
+        case(MajorSixth, MinorSeventh),
+        case(MajorSeventh, DominantSeventh),
+        case(DominantSeventhSuspendedFourth, MinorEleventh),
+        case(MinorEleventh, MajorThirteenth)
+    )]
+    fn test_ord(smaller: ChordType, larger: ChordType) {
+        assert!(smaller < larger);
+        assert!(larger > smaller);
+    }
 }
@@ -1,22 +1,41 @@
-use std::{iter::Sum, ops::Add};
+use std::{fmt, iter::Sum, ops::Add};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// The distance between two voicings combining semitone distance
 /// and fingering distance as a tuple.
-pub struct Distance(u8, u8);
+///
+/// The components are wide enough to hold the sum of the distances along
+/// a long chord sequence, not only the distance between two voicings.
+pub struct Distance(u32, u32);
 
 impl Distance {
-    pub fn new(semitone_distance: u8, fingering_distance: u8) -> Self {
+    pub fn new(semitone_distance: u32, fingering_distance: u32) -> Self {
         Self(semitone_distance, fingering_distance)
     }
 
-    pub fn semitone_distance(&self) -> u8 {
+    pub fn semitone_distance(&self) -> u32 {
         self.0
     }
 
-    pub fn fingering_distance(&self) -> u8 {
+    pub fn fingering_distance(&self) -> u32 {
         self.1
     }
+
+    /// Return the key by which distances are compared: the semitone
+    /// distance first, the fingering distance as a tie-breaker.
+    pub fn total(&self) -> (u32, u32) {
+        (self.0, self.1)
+    }
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "semitone distance: {}, fingering distance: {}",
+            self.0, self.1
+        )
+    }
 }
 
 impl Add for Distance {
@@ -35,3 +54,63 @@ impl<'a> Sum<&'a Self> for Distance {
         iter.fold(Self(0, 0), |a, b| Self(a.0 + b.0, a.1 + b.1))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        dist1,
+        dist2,
+        case(Distance::new(0, 0), Distance::new(0, 1)),
+        case(Distance::new(0, 9), Distance::new(1, 0)),
+        case(Distance::new(2, 3), Distance::new(2, 4)),
+        case(Distance::new(2, 9), Distance::new(3, 1))
+    )]
+    fn test_ord(dist1: Distance, dist2: Distance) {
+        assert!(dist1 < dist2);
+        assert!(dist1.total() < dist2.total());
+    }
+
+    #[rstest(
+        dists,
+        sum,
+        case(vec![], Distance::new(0, 0)),
+        case(vec![Distance::new(1, 2)], Distance::new(1, 2)),
+        case(vec![Distance::new(1, 2), Distance::new(3, 4)], Distance::new(4, 6)),
+        case(
+            vec![Distance::new(0, 1), Distance::default(), Distance::new(5, 0)],
+            Distance::new(5, 1)
+        ),
+        case(vec![Distance::new(200, 10); 3], Distance::new(600, 30)),
+    )]
+    fn test_sum(dists: Vec<Distance>, sum: Distance) {
+        assert_eq!(dists.iter().sum::<Distance>(), sum);
+        assert_eq!(dists.iter().fold(Distance::default(), |a, b| a + *b), sum);
+    }
+
+    #[rstest(
+        dist,
+        semitone_distance,
+        fingering_distance,
+        case(Distance::new(0, 0), 0, 0),
+        case(Distance::new(3, 7), 3, 7)
+    )]
+    fn test_accessors(dist: Distance, semitone_distance: u32, fingering_distance: u32) {
+        assert_eq!(dist.semitone_distance(), semitone_distance);
+        assert_eq!(dist.fingering_distance(), fingering_distance);
+        assert_eq!(dist.total(), (semitone_distance, fingering_distance));
+    }
+
+    #[rstest(
+        dist,
+        s,
+        case(Distance::new(0, 0), "semitone distance: 0, fingering distance: 0"),
+        case(Distance::new(3, 7), "semitone distance: 3, fingering distance: 7")
+    )]
+    fn test_to_str(dist: Distance, s: &str) {
+        assert_eq!(dist.to_string(), s);
+    }
+}
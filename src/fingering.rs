@@ -72,7 +72,8 @@ mod tests {
     #[rstest(
         frets, finger_positions,
         case([0, 0, 0, 0], [(0, 0), (0, 0), (0, 0), (0, 0)]),
-        case([2, 2, 2, 0], [(1, 2), (2, 2), (3, 2), (0, 0)]),
+        case([2, 2, 2, 0], [(1, 2), (0, 0), (0, 0), (0, 0)]),
+        case([2, 2, 0, 2], [(1, 2), (2, 2), (4, 2), (0, 0)]),
         case([2, 2, 2, 2], [(1, 2), (0, 0), (0, 0), (0, 0)]),
     )]
     fn test_from_voicing(
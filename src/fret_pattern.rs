@@ -1,55 +1,144 @@
-use std::{convert::TryInto, slice::Iter, str::FromStr};
+use std::{
+    convert::{TryFrom, TryInto},
+    slice::Iter,
+    str::FromStr,
+};
 
 use crate::{FretID, STRING_COUNT};
 
 /// Custom error for strings that cannot be parsed into a fret pattern.
-#[derive(Debug, thiserror::Error)]
-#[error("fret pattern has wrong format (should be something like 1234 or '7 8 9 10')")]
-pub struct ParseFretPatternError;
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ParseFretPatternError {
+    /// A token is neither a fret number nor `x` for a muted string.
+    #[error("fret pattern has wrong format (should be something like 1234 or '7 8 9 10'): invalid fret '{0}'")]
+    InvalidFret(String),
+    /// The pattern does not contain one fret per string.
+    #[error(transparent)]
+    FretCount(#[from] FretCountError),
+    /// The pattern is written without separators but has too many digits,
+    /// e.g. `78910` instead of `7 8 9 10`.
+    #[error("fret pattern has wrong format (should be something like 1234 or '7 8 9 10'): frets above 9 need to be separated, e.g. by spaces")]
+    MissingSeparators,
+}
+
+/// Characters that can be used to separate the frets of a pattern,
+/// e.g. `7 8 9 10`, `7,8,9,10` or `7-8-9-10`.
+const SEPARATORS: [char; 3] = [' ', ',', '-'];
+
+/// Return `true` if `c` separates the frets of a pattern.
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || SEPARATORS.contains(&c)
+}
+
+/// Custom error for fret lists that do not contain one fret per string.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("fret pattern needs exactly {STRING_COUNT} frets, found {0}")]
+pub struct FretCountError(usize);
 
 /// A pattern of frets to press down for playing a chord.
 ///
 /// Each index of the array corresponds to a ukulele string.
+/// Strings that are not played are marked as muted (written as `x`).
+/// Their fret is set to 0.
 #[derive(Debug, Copy, Clone)]
 pub struct FretPattern {
     frets: [FretID; STRING_COUNT],
+    muted: [bool; STRING_COUNT],
 }
 
 impl FretPattern {
     pub fn iter(&self) -> Iter<'_, FretID> {
         self.frets.iter()
     }
+
+    /// Return an iterator over the strings' muted states.
+    pub fn muted(&self) -> Iter<'_, bool> {
+        self.muted.iter()
+    }
+
+    /// Return the pattern with the order of the strings reversed, e.g. to
+    /// read a pattern written from the A string to the G string.
+    pub fn reversed(mut self) -> Self {
+        self.frets.reverse();
+        self.muted.reverse();
+        self
+    }
 }
 
 impl From<[FretID; STRING_COUNT]> for FretPattern {
     fn from(frets: [FretID; STRING_COUNT]) -> Self {
-        Self { frets }
+        Self {
+            frets,
+            muted: [false; STRING_COUNT],
+        }
     }
 }
 
 impl FromStr for FretPattern {
     type Err = ParseFretPatternError;
 
+    /// Parse a fret pattern such as `2220` or `7 8 9 10`. Muted strings are written as `x`.
+    ///
+    /// Frets can be separated by whitespace, commas or dashes (e.g. `2,2,2,0` or `2-2-2-0`).
+    /// Without separators, each character is read as a fret, so frets above 9
+    /// always need to be separated.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Handle both patterns containing spaces such as "1 2 3 4" as well as patterns
-        // without spaces such as "1234".
-        let split: Vec<String> = match s.contains(' ') {
-            true => s.split(' ').map(|c| c.to_string()).collect(),
+        let s = s.trim();
+
+        // Handle both patterns containing separators such as "1 2 3 4" as well as patterns
+        // without separators such as "1234".
+        let separated = s.contains(is_separator);
+        let split: Vec<String> = match separated {
+            true => s
+                .split(is_separator)
+                .filter(|t| !t.is_empty())
+                .map(|t| t.to_string())
+                .collect(),
             false => s.chars().map(|c| c.to_string()).collect(),
         };
 
-        // Parse out numbers in the pattern.
-        let fret_res: Result<Vec<FretID>, _> = split.iter().map(|s| s.parse()).collect();
+        // Parse out numbers in the pattern. Muted strings are represented by `None`.
+        let fret_vec = split
+            .iter()
+            .map(|t| match t.as_str() {
+                "x" | "X" => Ok(None),
+                _ => t
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| ParseFretPatternError::InvalidFret(t.clone())),
+            })
+            .collect::<Result<Vec<Option<FretID>>, _>>()?;
 
-        if let Ok(fret_vec) = fret_res {
-            // Check for the correct number of frets.
-            let res: Result<[FretID; STRING_COUNT], _> = fret_vec.try_into();
-            if let Ok(frets) = res {
-                return Ok(Self::from(frets));
-            }
-        }
+        // Check for the correct number of frets.
+        let count = fret_vec.len();
+        let fret_opts: [Option<FretID>; STRING_COUNT] =
+            fret_vec.try_into().map_err(|_| match separated {
+                false if count > STRING_COUNT => ParseFretPatternError::MissingSeparators,
+                _ => FretCountError(count).into(),
+            })?;
 
-        Err(ParseFretPatternError)
+        let frets = fret_opts.map(|f| f.unwrap_or_default());
+        let muted = fret_opts.map(|f| f.is_none());
+        Ok(Self { frets, muted })
+    }
+}
+
+impl TryFrom<&[FretID]> for FretPattern {
+    type Error = FretCountError;
+
+    fn try_from(frets: &[FretID]) -> Result<Self, Self::Error> {
+        let frets: [FretID; STRING_COUNT] =
+            frets.try_into().map_err(|_| FretCountError(frets.len()))?;
+
+        Ok(Self::from(frets))
+    }
+}
+
+impl TryFrom<Vec<FretID>> for FretPattern {
+    type Error = FretCountError;
+
+    fn try_from(frets: Vec<FretID>) -> Result<Self, Self::Error> {
+        Self::try_from(&frets[..])
     }
 }
 
@@ -64,13 +153,128 @@ mod tests {
         case("2220", [2, 2, 2, 0]),
         case("2 2 2 0", [2, 2, 2, 0]),
         case("7 8 9 10", [7, 8, 9, 10]),
+        case("2220\n", [2, 2, 2, 0]),
+        case("  2 2 2 0 ", [2, 2, 2, 0]),
+        case("2\t2\t2\t0", [2, 2, 2, 0]),
+        case("2,2,2,0", [2, 2, 2, 0]),
+        case("2-2-2-0", [2, 2, 2, 0]),
+        case("2, 2, 2, 0", [2, 2, 2, 0]),
+        case("7-8,9 10", [7, 8, 9, 10]),
+        case("7,8,9,10", [7, 8, 9, 10]),
+        case("10-12-12-12", [10, 12, 12, 12]),
     )]
     fn test_from_str(fret_pattern: FretPattern, frets: [FretID; STRING_COUNT]) {
         assert_eq!(fret_pattern.frets, frets);
+        assert_eq!(fret_pattern.muted, [false; STRING_COUNT]);
     }
 
-    #[rstest(s, case(""), case("Cm"), case("222"), case("22201"))]
+    #[rstest(
+        fret_pattern, frets, muted,
+        case("x032", [0, 0, 3, 2], [true, false, false, false]),
+        case("0x32", [0, 0, 3, 2], [false, true, false, false]),
+        case("X 7 8 10", [0, 7, 8, 10], [true, false, false, false]),
+        case("x-7-8-10", [0, 7, 8, 10], [true, false, false, false]),
+        case("xxxx", [0, 0, 0, 0], [true, true, true, true]),
+    )]
+    fn test_from_str_muted(
+        fret_pattern: FretPattern,
+        frets: [FretID; STRING_COUNT],
+        muted: [bool; STRING_COUNT],
+    ) {
+        assert_eq!(fret_pattern.frets, frets);
+        assert_eq!(fret_pattern.muted, muted);
+    }
+
+    #[rstest(
+        fret_pattern, frets, muted,
+        case("0003", [3, 0, 0, 0], [false, false, false, false]),
+        case("x032", [2, 3, 0, 0], [false, false, false, true]),
+    )]
+    fn test_reversed(
+        fret_pattern: FretPattern,
+        frets: [FretID; STRING_COUNT],
+        muted: [bool; STRING_COUNT],
+    ) {
+        let reversed = fret_pattern.reversed();
+        assert_eq!(reversed.frets, frets);
+        assert_eq!(reversed.muted, muted);
+    }
+
+    #[rstest(
+        s,
+        case(""),
+        case("Cm"),
+        case("222"),
+        case("22201"),
+        case("x22"),
+        case("y222")
+    )]
     fn test_from_str_fail(s: &str) {
         assert!(FretPattern::from_str(s).is_err());
     }
+
+    #[rstest(
+        s,
+        error,
+        case("y222", ParseFretPatternError::InvalidFret("y".to_string())),
+        case("2 2 2 a", ParseFretPatternError::InvalidFret("a".to_string())),
+        case("2;2;2;0", ParseFretPatternError::InvalidFret(";".to_string())),
+        case("2.2 2 2", ParseFretPatternError::InvalidFret("2.2".to_string())),
+        case("222", FretCountError(3).into()),
+        case("2,2,2", FretCountError(3).into()),
+        case("7 8 9 10 11", FretCountError(5).into()),
+        // Without separators, each digit is a fret of its own.
+        case("78910", ParseFretPatternError::MissingSeparators),
+        case("22201", ParseFretPatternError::MissingSeparators),
+    )]
+    fn test_from_str_error(s: &str, error: ParseFretPatternError) {
+        assert_eq!(FretPattern::from_str(s).unwrap_err(), error);
+    }
+
+    #[test]
+    fn test_parse_fret_pattern_error() {
+        let prefix = "fret pattern has wrong format (should be something like 1234 or '7 8 9 10')";
+        assert_eq!(
+            ParseFretPatternError::InvalidFret("y".to_string()).to_string(),
+            format!("{prefix}: invalid fret 'y'")
+        );
+        assert_eq!(
+            ParseFretPatternError::from(FretCountError(3)).to_string(),
+            "fret pattern needs exactly 4 frets, found 3"
+        );
+    }
+
+    #[rstest(
+        frets,
+        case(vec![0, 0, 0, 3]),
+        case(vec![7, 8, 9, 10]),
+    )]
+    fn test_try_from(frets: Vec<FretID>) {
+        let fret_pattern = FretPattern::try_from(&frets[..]).unwrap();
+        assert_eq!(fret_pattern.frets[..], frets[..]);
+        assert_eq!(fret_pattern.muted, [false; STRING_COUNT]);
+
+        let fret_pattern = FretPattern::try_from(frets.clone()).unwrap();
+        assert_eq!(fret_pattern.frets[..], frets[..]);
+    }
+
+    #[rstest(
+        frets,
+        case(vec![]),
+        case(vec![0, 0, 3]),
+        case(vec![0, 0, 0, 3, 5]),
+    )]
+    fn test_try_from_fail(frets: Vec<FretID>) {
+        let err = FretCountError(frets.len());
+        assert_eq!(FretPattern::try_from(&frets[..]).unwrap_err(), err);
+        assert_eq!(FretPattern::try_from(frets).unwrap_err(), err);
+    }
+
+    #[test]
+    fn test_fret_count_error() {
+        assert_eq!(
+            FretCountError(3).to_string(),
+            "fret pattern needs exactly 4 frets, found 3"
+        );
+    }
 }
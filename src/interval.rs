@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{cmp::Ordering, fmt, str::FromStr};
 
 use crate::{Semitones, StaffSteps};
 
@@ -11,17 +11,22 @@ pub struct ParseIntervalError {
 
 /// An interval is the difference between two notes.
 ///
+/// Intervals are ordered by the number of semitones they encompass and,
+/// for intervals of the same size, by their number, e.g. A4 comes before d5.
+///
 /// https://en.wikipedia.org/wiki/Interval_(music)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Interval {
     PerfectUnison,
     MajorSecond,
     MinorThird,
     MajorThird,
     PerfectFourth,
+    AugmentedFourth,
     DiminishedFifth,
     PerfectFifth,
     AugmentedFifth,
+    MinorSixth,
     MajorSixth,
     DiminishedSeventh,
     MinorSeventh,
@@ -30,10 +35,86 @@ pub enum Interval {
     MajorNinth,
     AugmentedNinth,
     PerfectEleventh,
+    AugmentedEleventh,
     MajorThirteenth,
 }
 
 impl Interval {
+    /// Iterator over the values of the Interval enum.
+    pub fn values() -> impl Iterator<Item = Self> {
+        use Interval::*;
+
+        [
+            PerfectUnison,
+            MajorSecond,
+            MinorThird,
+            MajorThird,
+            PerfectFourth,
+            AugmentedFourth,
+            DiminishedFifth,
+            PerfectFifth,
+            AugmentedFifth,
+            MinorSixth,
+            MajorSixth,
+            DiminishedSeventh,
+            MinorSeventh,
+            MajorSeventh,
+            MinorNinth,
+            MajorNinth,
+            AugmentedNinth,
+            PerfectEleventh,
+            AugmentedEleventh,
+            MajorThirteenth,
+        ]
+        .iter()
+        .copied()
+    }
+
+    /// Find the interval that encompasses the given number of semitones
+    /// and staff positions (if it is among the intervals we know).
+    fn find(semitones: Semitones, number: StaffSteps) -> Option<Self> {
+        Self::values().find(|i| i.to_semitones() == semitones && i.to_number() == number)
+    }
+
+    /// Return `true` if the interval spans at most an octave.
+    pub fn is_simple(self) -> bool {
+        self.to_number() < 8
+    }
+
+    /// Return the inversion of a simple interval, e.g. m6 for M3 or P4 for P5.
+    /// The numbers of an interval and its inversion add up to 9, their semitones
+    /// add up to 12.
+    ///
+    /// Return `None` for compound intervals and for inversions that are not
+    /// among the intervals we know (e.g. the inversion of P1 would be P8).
+    pub fn invert(self) -> Option<Self> {
+        if !self.is_simple() {
+            return None;
+        }
+
+        Self::find(12 - self.to_semitones(), 9 - self.to_number())
+    }
+
+    /// Return the compound interval that is an octave wider than this
+    /// simple interval, e.g. M9 for M2.
+    pub fn to_compound(self) -> Option<Self> {
+        if !self.is_simple() {
+            return None;
+        }
+
+        Self::find(self.to_semitones() + 12, self.to_number() + 7)
+    }
+
+    /// Return the simple interval that is an octave narrower than this
+    /// compound interval, e.g. M2 for M9. Simple intervals are returned unchanged.
+    pub fn simple(self) -> Option<Self> {
+        if self.is_simple() {
+            return Some(self);
+        }
+
+        Self::find(self.to_semitones() - 12, self.to_number() - 7)
+    }
+
     /// Return the number of semitones that the interval encompasses.
     pub fn to_semitones(self) -> Semitones {
         use Interval::*;
@@ -44,9 +125,11 @@ impl Interval {
             MinorThird => 3,
             MajorThird => 4,
             PerfectFourth => 5,
+            AugmentedFourth => 6,
             DiminishedFifth => 6,
             PerfectFifth => 7,
             AugmentedFifth => 8,
+            MinorSixth => 8,
             MajorSixth => 9,
             DiminishedSeventh => 9,
             MinorSeventh => 10,
@@ -55,6 +138,7 @@ impl Interval {
             MajorNinth => 14,
             AugmentedNinth => 15,
             PerfectEleventh => 17,
+            AugmentedEleventh => 18,
             MajorThirteenth => 21,
         }
     }
@@ -70,9 +154,11 @@ impl Interval {
             MinorThird => 3,
             MajorThird => 3,
             PerfectFourth => 4,
+            AugmentedFourth => 4,
             DiminishedFifth => 5,
             PerfectFifth => 5,
             AugmentedFifth => 5,
+            MinorSixth => 6,
             MajorSixth => 6,
             DiminishedSeventh => 7,
             MinorSeventh => 7,
@@ -81,11 +167,55 @@ impl Interval {
             MajorNinth => 9,
             AugmentedNinth => 9,
             PerfectEleventh => 11,
+            AugmentedEleventh => 11,
             MajorThirteenth => 13,
         }
     }
 }
 
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.to_semitones(), self.to_number()).cmp(&(other.to_semitones(), other.to_number()))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Interval::*;
+
+        let s = match self {
+            PerfectUnison => "P1",
+            MajorSecond => "M2",
+            MinorThird => "m3",
+            MajorThird => "M3",
+            PerfectFourth => "P4",
+            AugmentedFourth => "A4",
+            DiminishedFifth => "d5",
+            PerfectFifth => "P5",
+            AugmentedFifth => "A5",
+            MinorSixth => "m6",
+            MajorSixth => "M6",
+            DiminishedSeventh => "d7",
+            MinorSeventh => "m7",
+            MajorSeventh => "M7",
+            MinorNinth => "m9",
+            MajorNinth => "M9",
+            AugmentedNinth => "A9",
+            PerfectEleventh => "P11",
+            AugmentedEleventh => "A11",
+            MajorThirteenth => "M13",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
 impl FromStr for Interval {
     type Err = ParseIntervalError;
 
@@ -100,9 +230,11 @@ impl FromStr for Interval {
             "m3" => MinorThird,
             "M3" => MajorThird,
             "P4" => PerfectFourth,
+            "A4" => AugmentedFourth,
             "d5" => DiminishedFifth,
             "P5" => PerfectFifth,
             "A5" => AugmentedFifth,
+            "m6" => MinorSixth,
             "M6" => MajorSixth,
             "d7" => DiminishedSeventh,
             "m7" => MinorSeventh,
@@ -111,6 +243,7 @@ impl FromStr for Interval {
             "M9" => MajorNinth,
             "A9" => AugmentedNinth,
             "P11" => PerfectEleventh,
+            "A11" => AugmentedEleventh,
             "M13" => MajorThirteenth,
             _ => return Err(ParseIntervalError { name }),
         };
@@ -118,3 +251,137 @@ impl FromStr for Interval {
         Ok(interval)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        s,
+        case("P1"),
+        case("M2"),
+        case("m3"),
+        case("M3"),
+        case("P4"),
+        case("A4"),
+        case("d5"),
+        case("P5"),
+        case("A5"),
+        case("m6"),
+        case("M6"),
+        case("d7"),
+        case("m7"),
+        case("M7"),
+        case("m9"),
+        case("M9"),
+        case("A9"),
+        case("P11"),
+        case("A11"),
+        case("M13")
+    )]
+    fn test_from_and_to_str(s: &str) {
+        let interval = Interval::from_str(s).unwrap();
+        assert_eq!(format!("{interval}"), s);
+    }
+
+    #[rstest(
+        interval,
+        semitones,
+        number,
+        case(Interval::AugmentedFourth, 6, 4),
+        case(Interval::DiminishedFifth, 6, 5),
+        case(Interval::AugmentedFifth, 8, 5),
+        case(Interval::MinorSixth, 8, 6)
+    )]
+    fn test_semitones_and_number(interval: Interval, semitones: Semitones, number: StaffSteps) {
+        assert_eq!(interval.to_semitones(), semitones);
+        assert_eq!(interval.to_number(), number);
+    }
+
+    #[test]
+    fn test_ord() {
+        let intervals: Vec<Interval> = Interval::values().collect();
+
+        // The intervals are listed in ascending order.
+        for pair in intervals.windows(2) {
+            let (i1, i2) = (pair[0], pair[1]);
+            assert!(i1 < i2);
+            assert!(i1.to_semitones() <= i2.to_semitones());
+            assert!(i1.to_semitones() < i2.to_semitones() || i1.to_number() < i2.to_number());
+        }
+
+        // Compound intervals are ordered by their size as well.
+        assert!(Interval::MajorSeventh < Interval::MinorNinth);
+        assert!(Interval::AugmentedNinth < Interval::PerfectEleventh);
+    }
+
+    #[rstest(
+        interval1,
+        interval2,
+        case("A4", "d5"),
+        case("A5", "m6"),
+        case("M6", "d7")
+    )]
+    fn test_ord_same_semitones(interval1: Interval, interval2: Interval) {
+        assert!(interval1 < interval2);
+        assert_eq!(interval1.cmp(&interval2), Ordering::Less);
+    }
+
+    #[rstest(
+        interval,
+        inversion,
+        case("M2", "m7"),
+        case("m3", "M6"),
+        case("M3", "m6"),
+        case("P4", "P5"),
+        case("A4", "d5")
+    )]
+    fn test_invert(interval: Interval, inversion: Interval) {
+        assert_eq!(interval.invert(), Some(inversion));
+        assert_eq!(inversion.invert(), Some(interval));
+    }
+
+    #[rstest(interval, case("P1"), case("A5"), case("d7"), case("M7"), case("M9"))]
+    fn test_invert_unknown(interval: Interval) {
+        assert_eq!(interval.invert(), None);
+    }
+
+    #[rstest(
+        interval,
+        compound,
+        case("M2", Some("M9")),
+        case("P4", Some("P11")),
+        case("A4", Some("A11")),
+        case("M6", Some("M13")),
+        case("m3", None),
+        case("P5", None),
+        case("M9", None)
+    )]
+    fn test_to_compound(interval: Interval, compound: Option<&str>) {
+        let compound = compound.map(|c| Interval::from_str(c).unwrap());
+        assert_eq!(interval.to_compound(), compound);
+
+        if let Some(compound) = compound {
+            assert_eq!(compound.simple(), Some(interval));
+        }
+    }
+
+    #[rstest(
+        interval,
+        simple,
+        case("P1", Some("P1")),
+        case("M7", Some("M7")),
+        case("M9", Some("M2")),
+        case("P11", Some("P4")),
+        case("A11", Some("A4")),
+        case("M13", Some("M6")),
+        case("m9", None),
+        case("A9", None)
+    )]
+    fn test_simple(interval: Interval, simple: Option<&str>) {
+        let simple = simple.map(|s| Interval::from_str(s).unwrap());
+        assert_eq!(interval.simple(), simple);
+    }
+}
@@ -1,7 +1,9 @@
 #![allow(clippy::upper_case_acronyms)]
 
+pub mod accidental;
 pub mod chord;
 pub mod chord_chart;
+pub mod chord_pro;
 pub mod chord_sequence;
 pub mod chord_type;
 pub mod distance;
@@ -10,24 +12,31 @@ pub mod fret_pattern;
 pub mod interval;
 pub mod note;
 pub mod pitch_class;
+pub mod quality;
+pub mod shape;
 pub mod staff_position;
 pub mod tuning;
 pub mod voicing;
+pub mod voicing_cache;
 pub mod voicing_graph;
 
+pub use accidental::Accidental;
 pub use chord::Chord;
-pub use chord_chart::ChordChart;
+pub use chord_chart::{ChartStyle, ChordChart, DiagramStyle};
 pub use chord_sequence::ChordSequence;
-pub use chord_type::{ChordType, NoMatchingChordTypeFoundError};
+pub use chord_type::{ChordDetectionError, ChordType};
 pub use distance::Distance;
 pub use fingering::Fingering;
 pub use fret_pattern::FretPattern;
 pub use interval::Interval;
 pub use note::Note;
 pub use pitch_class::PitchClass;
+pub use quality::Quality;
+pub use shape::Shape;
 pub use staff_position::StaffPosition;
 pub use tuning::Tuning;
-pub use voicing::Voicing;
+pub use voicing::{BarreInfo, Unplayable, Voicing, VoicingError, VoicingType};
+pub use voicing_cache::VoicingCache;
 pub use voicing_graph::VoicingGraph;
 
 /// Number of strings on our string instrument.
@@ -39,9 +48,17 @@ pub const FINGER_COUNT: usize = 4;
 /// Number of pitch classes.
 pub const PITCH_CLASS_COUNT: Semitones = 12;
 
+/// Maximal possible fret ID, which is also the default number of frets.
+/// According to Wikipedia, the biggest ukulele type (baritone) has 21 frets.
+pub const MAX_FRET_ID: FretID = 21;
+
 /// Minimal number of frets to be shown in a chord chart.
 pub const MIN_CHART_WIDTH: Semitones = 4;
 
+/// Number of frets covered by a position on the fretboard, i.e.
+/// the frets that can be reached without moving the hand.
+pub const POSITION_WIDTH: Semitones = 4;
+
 /// The ID of a fret on the fretboard. 0 corresponds to the nut,
 /// 1 corresponds to the first fret, 2 to the second etc.
 pub type FretID = u8;
@@ -54,6 +71,11 @@ pub type Semitones = u8;
 /// to another.
 pub type StaffSteps = u8;
 
+/// The octave of a note in scientific pitch notation, e.g. 4 for
+/// middle C (C4). Octaves start with the note C. Notes below C0, such as
+/// the lowest MIDI notes, are in negative octaves.
+pub type Octave = i8;
+
 /// The position of a finger on a certain string in a certain fret.
 /// For example, (3, 4) depicts the fourth fret on the third string.
 pub type FingerPosition = (u8, u8);
@@ -63,12 +85,27 @@ pub type FingerPosition = (u8, u8);
 /// the note that is played if this fret is pressed down.
 pub type UkeString = (Note, FretID, Note);
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct VoicingConfig {
     pub tuning: Tuning,
     pub min_fret: FretID,
     pub max_fret: FretID,
     pub max_span: Semitones,
+    /// Accept voicings that leave out some of the chord's optional notes.
+    pub allow_omissions: bool,
+    /// Tune the G string (or its counterpart) an octave lower.
+    pub low_g: bool,
+    /// List voicings that double the chord's root before the ones
+    /// doubling another note.
+    pub prefer_root_doubling: bool,
+    /// Accept only voicings whose lowest-sounding note is the chord's root.
+    pub only_root_position: bool,
+    /// Reject voicings that play the same note in the same octave on more
+    /// than one string. Notes doubled an octave apart are still accepted.
+    pub deny_duplicate_notes: bool,
+    /// Number of semitones by which all strings are tuned up (or down
+    /// if negative) compared to `tuning`.
+    pub tuning_offset: i8,
 }
 
 impl Default for VoicingConfig {
@@ -78,6 +115,26 @@ impl Default for VoicingConfig {
             min_fret: 0,
             max_fret: 12,
             max_span: 4,
+            allow_omissions: false,
+            low_g: false,
+            prefer_root_doubling: false,
+            only_root_position: false,
+            deny_duplicate_notes: false,
+            tuning_offset: 0,
         }
     }
 }
+
+impl VoicingConfig {
+    /// Restrict the voicings to the given position on the fretboard.
+    ///
+    /// Position N maps to the frets N to N + 3, so only voicings whose
+    /// fingers all fall within this window (and that don't use any open
+    /// strings) are accepted.
+    pub fn with_position(mut self, position: FretID) -> Self {
+        self.min_fret = position;
+        self.max_fret = position.saturating_add(POSITION_WIDTH - 1);
+        self.max_span = POSITION_WIDTH;
+        self
+    }
+}
@@ -1,15 +1,23 @@
-use clap::Parser;
+use std::{
+    cmp::max,
+    convert::TryFrom,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use clap::{error::ErrorKind, CommandFactory, Parser, ValueEnum};
 use itertools::Itertools;
 use lazy_static::lazy_static;
+use serde_json::json;
 use ukebox::{
-    Chord, ChordChart, ChordSequence, ChordType, FretID, FretPattern, Semitones, Tuning, Voicing,
-    VoicingConfig, VoicingGraph,
+    chord_pro, voicing_graph::MAX_DIST, Accidental, Chord, ChordChart, ChordSequence, ChordType,
+    DiagramStyle, Distance, FretID, FretPattern, Note, PitchClass, Semitones, Shape, Tuning,
+    Voicing, VoicingConfig, VoicingGraph, MAX_FRET_ID, PITCH_CLASS_COUNT,
 };
 
-/// Maximal possible fret ID.
-/// According to Wikipedia, the biggest ukulele type (baritone) has 21 frets.
-const MAX_FRET_ID: FretID = 21;
-
 /// Maximal span of frets.
 /// Playing a chord that spans more than 5 frets seems anatomically impossible to me.
 const MAX_SPAN: Semitones = 5;
@@ -19,8 +27,9 @@ lazy_static! {
     static ref DEFAULT_CONFIG: VoicingConfig = VoicingConfig::default();
     static ref TUNING_STR: String = DEFAULT_CONFIG.tuning.to_string();
     static ref MIN_FRET_STR: String = DEFAULT_CONFIG.min_fret.to_string();
-    static ref MAX_FRET_STR: String = DEFAULT_CONFIG.max_fret.to_string();
+    static ref FRETS_STR: String = MAX_FRET_ID.to_string();
     static ref MAX_SPAN_STR: String = DEFAULT_CONFIG.max_span.to_string();
+    static ref MAX_JUMP_STR: String = MAX_DIST.to_string();
 }
 
 #[derive(Parser)]
@@ -28,6 +37,26 @@ struct Ukebox {
     /// Type of tuning to be used
     #[arg(short, long, global = true, value_name = "TUNING", default_value = &**TUNING_STR, value_enum)]
     tuning: Tuning,
+    /// Number of semitones by which all strings are tuned up (e.g. 1, +1) or down (e.g. -1)
+    #[arg(
+        long,
+        global = true,
+        value_name = "SEMITONES",
+        allow_hyphen_values = true,
+        default_value = "0"
+    )]
+    tuning_offset: i8,
+    /// Number of frets on the instrument (e.g. 12 or 15 for a soprano ukulele),
+    /// which bounds all fret options
+    #[arg(long, global = true, value_name = "FRET_COUNT", default_value = &**FRETS_STR, value_parser = clap::value_parser!(FretID).range(1..=MAX_FRET_ID as i64))]
+    frets: FretID,
+    /// Restrict the output to ASCII characters (e.g. for legacy consoles)
+    #[arg(long, global = true)]
+    ascii_only: bool,
+    /// Read fret patterns and print chord charts from the A string to the G string
+    /// (or their counterparts) instead of the other way round
+    #[arg(long, global = true)]
+    reverse_strings: bool,
     #[command(subcommand)]
     cmd: Subcommand,
 }
@@ -36,6 +65,8 @@ struct Ukebox {
 enum Subcommand {
     /// List all supported chord types and symbols
     Chords {},
+    /// List all supported tunings and their open string notes
+    Tunings {},
     /// Chord chart lookup
     ///
     /// Enter note names as capital letters A - G.
@@ -48,26 +79,217 @@ enum Subcommand {
         /// Print out all voicings of <chord> that fulfill the given conditions
         #[arg(short, long)]
         all: bool,
+        /// Fall back to a simpler version of <chord> (e.g. C7 for C13) if no voicing is found
+        #[arg(long)]
+        simplify: bool,
+        /// Suggest how to relax the given conditions if no voicing is found
+        #[arg(long)]
+        suggest: bool,
+        /// Format of the output
+        #[arg(long, value_name = "FORMAT", default_value = "text", value_enum)]
+        format: ChartFormat,
+        /// Order of the strings in the tablature output (--format tab)
+        #[arg(long, value_name = "ORDER", default_value = "high-first", value_enum)]
+        tab_string_order: TabStringOrder,
+        /// Print out the voicing of <chord> that is closest to the given fret pattern
+        #[arg(long, value_name = "FRET_PATTERN", conflicts_with = "all")]
+        near: Option<FretPattern>,
+        /// List the voicings closest to the given moveable shape first (e.g. A for 5433 when looking up C)
+        #[arg(long, value_name = "SHAPE", conflicts_with = "near", value_enum)]
+        prefer_shape: Option<Shape>,
+        /// Append the interval formula of <chord> to the chart header, e.g. (P1 M3 P5)
+        #[arg(long)]
+        show_formula: bool,
+        /// Print out the voicing of <chord> that is easiest to play (fewest fingers, small span,
+        /// no barre, low on the neck) instead of the lowest one
+        #[arg(long, conflicts_with_all = ["all", "near", "prefer_shape", "sort"])]
+        best: bool,
+        /// Order in which the voicings of <chord> are listed
+        #[arg(long, value_name = "ORDER", default_value = "fret", value_enum, conflicts_with_all = ["near", "prefer_shape", "prefer_root_doubling"])]
+        sort: VoicingOrder,
+        /// Print the number of voicings shown and how many of them use open strings
+        /// or a barre (e.g. with --all)
+        #[arg(long)]
+        summary: bool,
+        /// Number of chord charts to print side by side (e.g. with --all)
+        #[arg(long, value_name = "COUNT", default_value = "1", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=10), conflicts_with = "prefer_root_doubling")]
+        columns: usize,
         #[command(flatten)]
         voicing_opts: VoicingOpts,
-        /// Name of the chord to be shown
-        #[arg(value_name = "CHORD")]
-        chord: Chord,
+        #[command(flatten)]
+        chart_opts: ChartOpts,
+        /// Notes of the chord to be shown instead of its name, e.g. "C E G Bb" (the first note
+        /// is preferred as the root)
+        #[arg(long, value_name = "NOTES", conflicts_with = "chords")]
+        notes: Option<String>,
+        /// Names of the chords to be shown
+        #[arg(value_name = "CHORD", required_unless_present = "notes")]
+        chords: Vec<Chord>,
     },
     /// Chord name lookup
     Name {
+        /// Print out the note and interval that each chord interpretation assigns to every string
+        #[arg(short, long)]
+        all: bool,
+        /// Format of the output
+        #[arg(long, value_name = "FORMAT", default_value = "text", value_enum)]
+        format: OutputFormat,
+        /// Explain for each candidate root which chord type was detected (or why none was)
+        #[arg(short, long)]
+        verbose: bool,
+        /// Only print the simplest of several chord names for the same notes
+        /// (e.g. C6 but not Am7 for 0000)
+        #[arg(long)]
+        no_duplicate_names: bool,
+        /// Accidental used to spell the roots of the chords found (e.g. flat for Bb instead of A#)
+        #[arg(long, value_name = "ACCIDENTAL", default_value = "sharp", value_enum)]
+        accidentals: Accidental,
+        /// Read the fret patterns to be looked up from a file (one per line)
+        /// or from stdin if <PATH> is -
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["fret_pattern", "all", "verbose"])]
+        file: Option<PathBuf>,
+        /// Number of semitones to add (e.g. 1, +1) or to subtract (e.g. -1) before naming
+        /// the chord, e.g. to account for a capo
+        #[arg(
+            long,
+            value_name = "SEMITONES",
+            allow_hyphen_values = true,
+            default_value = "0"
+        )]
+        transpose: i8,
         /// A compact chart representing the finger positions of the chord to be looked up
+        /// (use x for muted strings)
+        #[arg(value_name = "FRET_PATTERN", required_unless_present = "file")]
+        fret_pattern: Option<FretPattern>,
+    },
+    /// Compare two chord shapes
+    Diff {
+        /// A compact chart representing the finger positions of the first chord
+        #[arg(value_name = "FRET_PATTERN")]
+        fret_pattern1: FretPattern,
+        /// A compact chart representing the finger positions of the second chord
+        #[arg(value_name = "FRET_PATTERN")]
+        fret_pattern2: FretPattern,
+        #[command(flatten)]
+        chart_opts: ChartOpts,
+    },
+    /// Chart the chord played with a fret pattern in one tuning for another tuning
+    Revoice {
+        /// Tuning in which <fret-pattern> is played [default: the value of --tuning]
+        #[arg(long, value_name = "TUNING", value_enum)]
+        from: Option<Tuning>,
+        /// Tuning for which to chart the chord
+        #[arg(long, value_name = "TUNING", value_enum)]
+        to: Tuning,
+        #[command(flatten)]
+        voicing_opts: VoicingOpts,
+        #[command(flatten)]
+        chart_opts: ChartOpts,
+        /// A compact chart representing the finger positions of the chord in the tuning
+        /// given by --from (use x for muted strings)
         #[arg(value_name = "FRET_PATTERN")]
         fret_pattern: FretPattern,
     },
     /// Voice leading for a sequence of chords
     VoiceLead {
+        /// Prefer voicings that keep notes shared between consecutive chords on the same string
+        #[arg(long)]
+        common_tones: bool,
+        /// Print the distance covered by each transition between consecutive chords
+        #[arg(long)]
+        explain: bool,
+        /// Only consider the <beam-width> easiest voicings of each chord (speeds up long sequences)
+        #[arg(long, value_name = "COUNT", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=100))]
+        beam_width: Option<usize>,
+        /// Maximal number of semitones by which the voicings of consecutive chords may differ
+        #[arg(long, value_name = "SEMITONES", default_value = &**MAX_JUMP_STR)]
+        max_jump: Semitones,
         #[command(flatten)]
         voicing_opts: VoicingOpts,
+        #[command(flatten)]
+        chart_opts: ChartOpts,
         /// Chord sequence
         #[arg(value_name = "CHORD_SEQUENCE")]
         chord_seq: ChordSequence,
     },
+    /// Print the chart of a random chord for practicing
+    Random {
+        /// Chord types to pick from, given by their symbols and separated by commas
+        /// (e.g. "maj7,m7,7"); all chord types if not given
+        #[arg(long = "type", value_name = "TYPES", value_delimiter = ',')]
+        types: Vec<ChordType>,
+        /// Number of random chords to print
+        #[arg(long, value_name = "COUNT", default_value = "1", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=100))]
+        count: usize,
+        /// Seed for picking the chords, to get the same chords again (e.g. for a repeated drill)
+        #[arg(long, value_name = "SEED")]
+        seed: Option<u64>,
+        #[command(flatten)]
+        voicing_opts: VoicingOpts,
+        #[command(flatten)]
+        chart_opts: ChartOpts,
+    },
+    /// Print the chord charts of all the chords used in a song in ChordPro format
+    Songsheet {
+        /// Number of chord charts to print side by side
+        #[arg(long, value_name = "COUNT", default_value = "4", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=10))]
+        columns: usize,
+        #[command(flatten)]
+        voicing_opts: VoicingOpts,
+        #[command(flatten)]
+        chart_opts: ChartOpts,
+        /// ChordPro file (e.g. song.cho) to read the chords from or - to read from stdin
+        #[arg(value_name = "PATH")]
+        file: PathBuf,
+    },
+    /// Show the notes on each string of the fretboard
+    Fretboard {
+        /// Maximal fret up to which to show the notes (by default 12 or the last fret if lower)
+        #[arg(long, value_name = "FRET_ID", value_parser = clap::value_parser!(FretID).range(0..=MAX_FRET_ID as i64))]
+        max_fret: Option<FretID>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// Structured data to be processed by other programs
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ChartFormat {
+    /// Chord charts
+    Text,
+    /// Tab-separated values with one row per voicing (e.g. for spreadsheets)
+    Tsv,
+    /// Tablature with one column per voicing
+    Tab,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum VoicingOrder {
+    /// Lowest position on the fretboard first
+    Fret,
+    /// Lowest sounding bass note first
+    Pitch,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TabStringOrder {
+    /// A string (or its counterpart) on top, G string at the bottom
+    HighFirst,
+    /// G string (or its counterpart) on top, A string at the bottom
+    LowFirst,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DuplicateNotes {
+    /// Accept voicings playing the same note in the same octave on several strings
+    Allow,
+    /// Reject voicings playing the same note in the same octave on several strings
+    Deny,
 }
 
 #[derive(Parser)]
@@ -75,9 +297,9 @@ pub struct VoicingOpts {
     /// Minimal fret (= minimal position) from which to play <chord>
     #[arg(long, value_name = "FRET_ID", default_value = &**MIN_FRET_STR, value_parser = clap::value_parser!(FretID).range(0..=MAX_FRET_ID as i64))]
     min_fret: FretID,
-    /// Maximal fret up to which to play <chord>
-    #[arg(long, value_name = "FRET_ID", default_value = &**MAX_FRET_STR, value_parser = clap::value_parser!(FretID).range(0..=MAX_FRET_ID as i64))]
-    max_fret: FretID,
+    /// Maximal fret up to which to play <chord> (by default 12 or the last fret if lower)
+    #[arg(long, value_name = "FRET_ID", value_parser = clap::value_parser!(FretID).range(0..=MAX_FRET_ID as i64))]
+    max_fret: Option<FretID>,
     /// Maximal span between the first and the last fret pressed down when playing <chord>
     #[arg(long, value_name = "FRET_COUNT", default_value = &**MAX_SPAN_STR, value_parser = clap::value_parser!(Semitones).range(0..=MAX_SPAN as i64))]
     max_span: Semitones,
@@ -89,11 +311,500 @@ pub struct VoicingOpts {
         default_value = "0"
     )]
     transpose: i8,
+    /// Allow voicings that leave out optional notes of <chord> (such as the fifth)
+    #[arg(long)]
+    allow_omissions: bool,
+    /// Play <chord> in the given position, i.e. within frets <position> to <position> + 3
+    #[arg(long, value_name = "FRET_ID", conflicts_with_all = ["min_fret", "max_fret", "max_span"], value_parser = clap::value_parser!(FretID).range(1..=MAX_FRET_ID as i64))]
+    position: Option<FretID>,
+    /// List voicings doubling the root of <chord> first and show which notes are doubled
+    #[arg(long)]
+    prefer_root_doubling: bool,
+    /// Only show voicings whose lowest-sounding note is the root of <chord> (i.e. no inversions)
+    #[arg(long)]
+    only_root_position: bool,
+    /// Whether to accept voicings playing the same note in the same octave on several strings
+    #[arg(long, value_name = "MODE", default_value = "allow", value_enum)]
+    duplicate_notes: DuplicateNotes,
+}
+
+impl VoicingOpts {
+    fn to_config(&self, tuning: Tuning, tuning_offset: i8, frets: FretID) -> VoicingConfig {
+        check_fret("--min-fret", self.min_fret, frets);
+
+        if let Some(position) = self.position {
+            check_fret("--position", position, frets);
+        }
+
+        let config = VoicingConfig {
+            tuning,
+            tuning_offset,
+            min_fret: self.min_fret,
+            max_fret: bounded_max_fret(self.max_fret, frets),
+            max_span: self.max_span,
+            allow_omissions: self.allow_omissions,
+            prefer_root_doubling: self.prefer_root_doubling,
+            only_root_position: self.only_root_position,
+            deny_duplicate_notes: matches!(self.duplicate_notes, DuplicateNotes::Deny),
+            ..Default::default()
+        };
+
+        match self.position {
+            Some(position) => in_position(config, position, frets),
+            None => config,
+        }
+    }
+}
+
+/// Restrict `config` to the given position on an instrument with `frets` frets
+/// (see `VoicingConfig::with_position`).
+fn in_position(config: VoicingConfig, position: FretID, frets: FretID) -> VoicingConfig {
+    let config = config.with_position(position);
+
+    // Positions near the end of the neck are cut off at the last fret.
+    VoicingConfig {
+        max_fret: config.max_fret.min(frets),
+        ..config
+    }
+}
+
+/// Exit with an error if `fret` (given with the option `arg`) is not
+/// on an instrument with `frets` frets.
+fn check_fret(arg: &str, fret: FretID, frets: FretID) {
+    if fret > frets {
+        let msg = format!("{arg} {fret} exceeds the number of frets ({frets})");
+        Ukebox::command()
+            .error(ErrorKind::ValueValidation, msg)
+            .exit();
+    }
+}
+
+/// Return the maximal fret to be used on an instrument with `frets` frets.
+/// If no maximal fret has been given, the default one is silently lowered to
+/// the last fret. Given values beyond the last fret are rejected.
+fn bounded_max_fret(max_fret: Option<FretID>, frets: FretID) -> FretID {
+    match max_fret {
+        Some(max_fret) => {
+            check_fret("--max-fret", max_fret, frets);
+            max_fret
+        }
+        None => DEFAULT_CONFIG.max_fret.min(frets),
+    }
+}
+
+#[derive(Parser)]
+pub struct ChartOpts {
+    /// Print the fret numbers above each chord chart
+    #[arg(long)]
+    fret_numbers: bool,
+    /// Mark the frets with position markers (inlays) above each chord chart
+    #[arg(long)]
+    inlays: bool,
+    /// Number of frets to show in each chord chart (at least the span of the chord)
+    #[arg(long, value_name = "FRET_COUNT", value_parser = clap::value_parser!(Semitones).range(1..=MAX_FRET_ID as i64))]
+    chart_width: Option<Semitones>,
+    /// Do not print the notes of the strings in front of each chord chart
+    #[arg(long)]
+    no_string_labels: bool,
+    /// Do not print the notes played on the strings behind each chord chart
+    #[arg(long)]
+    no_note_labels: bool,
+    /// Characters used to draw each chord chart (dash: o, | and finger numbers; dot: ○, + and ●)
+    #[arg(long, value_name = "STYLE", default_value = "dash", value_enum)]
+    diagram_style: DiagramStyle,
+    /// Mark the strings playing the root of the chord with R behind each chord chart
+    #[arg(long)]
+    highlight_root: bool,
+}
+
+impl ChartOpts {
+    /// Create a chord chart for `voicing` that is `width` frets wide
+    /// unless a different width has been requested. If requested, the
+    /// strings playing `root` are marked. With `ascii_only`, the dash
+    /// style is used regardless of the requested diagram style.
+    fn to_chart(
+        &self,
+        voicing: Voicing,
+        root: Option<Note>,
+        width: Semitones,
+        reverse_strings: bool,
+        ascii_only: bool,
+    ) -> ChordChart {
+        let width = self.chart_width.unwrap_or(width);
+        let span = voicing.get_span();
+
+        if span > width {
+            let msg = format!("chart width {width} is too small for a chord spanning {span} frets");
+            Ukebox::command()
+                .error(ErrorKind::ValueValidation, msg)
+                .exit();
+        }
+
+        ChordChart::new(voicing, width)
+            .with_fret_numbers(self.fret_numbers)
+            .with_inlays(self.inlays)
+            .with_diagram_style(match ascii_only {
+                true => DiagramStyle::Dash,
+                false => self.diagram_style,
+            })
+            .with_string_labels(!self.no_string_labels)
+            .with_note_labels(!self.no_note_labels)
+            .with_reverse_strings(reverse_strings)
+            .with_highlight(root.filter(|_| self.highlight_root))
+    }
+}
+
+/// Collect the chords found for `voicing` in a JSON object.
+fn chords_to_json(voicing: &Voicing, tuning: Tuning, chords: &[Chord]) -> serde_json::Value {
+    // Muted strings are represented by `null`.
+    let frets: Vec<_> = voicing
+        .frets()
+        .zip(voicing.muted())
+        .map(|(f, &m)| (!m).then_some(f))
+        .collect();
+
+    let pitch_classes: Vec<_> = voicing
+        .notes()
+        .map(|n| n.pitch_class)
+        .sorted()
+        .dedup()
+        .map(|pc| Note::from(pc).to_string())
+        .collect();
+
+    let chords: Vec<_> = chords
+        .iter()
+        .map(|chord| {
+            json!({
+                "name": chord.name(),
+                "root": chord.root.to_string(),
+                "chord_type": chord.chord_type.to_string(),
+                "notes": chord.notes.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    let quartal: Option<Vec<_>> = voicing
+        .quartal_notes()
+        .map(|notes| notes.iter().map(|n| n.to_string()).collect());
+
+    json!({
+        "frets": frets,
+        "tuning": tuning.to_string(),
+        "contains_open_string": voicing.contains_open_string(),
+        "pitch_classes": pitch_classes,
+        "chords": chords,
+        "quartal": quartal,
+    })
+}
+
+/// Return one line per chord in `chords` found for `voicing` for the output
+/// of the `name` subcommand. Equivalent names of symmetric chords are spelled
+/// with `accidentals`.
+fn chord_names(voicing: &Voicing, chords: &[Chord], accidentals: Accidental) -> Vec<String> {
+    // Voicings stacked in fourths are not covered by the (tertian) chord types,
+    // so they are named separately.
+    let quartal = voicing
+        .quartal_notes()
+        .map(|notes| format!("quartal ({})", notes.iter().join(" ")));
+
+    if chords.is_empty() {
+        let name = quartal.unwrap_or_else(|| "No matching chord was found".to_string());
+        return vec![name];
+    }
+
+    // Symmetric chords such as Cdim7 have several names for the same notes.
+    // List them in a single line instead of one line per root.
+    let mut listed: Vec<Chord> = vec![];
+    let mut names = vec![];
+
+    for chord in chords {
+        if listed.contains(chord) {
+            continue;
+        }
+
+        let equivalents: Vec<Chord> = chord
+            .equivalents()
+            .iter()
+            .map(|c| c.spelled_with(accidentals))
+            .collect();
+
+        match equivalents.is_empty() {
+            true => names.push(chord.to_string()),
+            false => {
+                let eq_names = equivalents.iter().map(|c| c.name()).join(" = ");
+                names.push(format!("{chord} (= {eq_names})"));
+            }
+        }
+
+        listed.extend(equivalents);
+    }
+
+    names.extend(quartal);
+    names
+}
+
+/// Keep only the first of several chords consisting of the same pitch classes,
+/// i.e. the one with the simplest chord type (see `Voicing::get_chords()`).
+fn without_duplicate_names(chords: Vec<Chord>) -> Vec<Chord> {
+    let mut kept: Vec<Chord> = vec![];
+
+    for chord in chords {
+        if !kept.iter().any(|c| c.same_pitches(&chord)) {
+            kept.push(chord);
+        }
+    }
+
+    kept
+}
+
+/// Read the whole content of the file at `path` or of stdin if `path` is `-`.
+fn read_input(path: &PathBuf) -> io::Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path)
+}
+
+/// Describe for each note of `voicing` taken as the root which chord type
+/// is detected from the differences in semitones to the other notes.
+fn explain_chord_detection(voicing: &Voicing) -> String {
+    voicing
+        .root_rotations()
+        .iter()
+        .map(|pitches| {
+            let root = Note::from(pitches[0]);
+            let diffs: Vec<Semitones> =
+                pitches.iter().map(|&pc| pc - pitches[0]).sorted().collect();
+
+            let result = match ChordType::try_from(&pitches[..]) {
+                Ok(chord_type) => {
+                    let required = chord_type.required_intervals().join(" ");
+                    let optional = chord_type
+                        .optional_intervals()
+                        .filter(|i| diffs.contains(&(i.to_semitones() % PITCH_CLASS_COUNT)))
+                        .join(" ");
+                    let optional = match optional.is_empty() {
+                        true => "-".to_string(),
+                        false => optional,
+                    };
+
+                    format!("{chord_type} (required: {required}, optional: {optional})")
+                }
+                Err(err) => err.to_string(),
+            };
+
+            format!(
+                "Root {root}: semitones {} -> {result}\n",
+                diffs.iter().join(" ")
+            )
+        })
+        .collect()
+}
+
+/// Determine the chord made up of the notes given with `--notes`,
+/// e.g. C7 for "C E G Bb", or exit with an error.
+fn chord_from_notes(s: &str) -> Chord {
+    let notes: Result<Vec<Note>, _> = s.split_whitespace().map(Note::from_str).collect();
+
+    let res = match notes {
+        Ok(notes) => Chord::from_notes(&notes).map_err(|err| err.to_string()),
+        Err(err) => Err(err.to_string()),
+    };
+
+    res.unwrap_or_else(|err| {
+        let msg = format!("notes '{s}' do not form a recognized chord: {err}");
+        Ukebox::command()
+            .error(ErrorKind::ValueValidation, msg)
+            .exit()
+    })
+}
+
+/// Describe the distance between two voicings in words,
+/// e.g. "2 semitones, 1 finger move".
+fn describe_distance(dist: Distance) -> String {
+    let plural = |count: u32| if count == 1 { "" } else { "s" };
+    let semitones = dist.semitone_distance();
+    let finger_moves = dist.fingering_distance();
+
+    format!(
+        "{semitones} semitone{}, {finger_moves} finger move{}",
+        plural(semitones),
+        plural(finger_moves)
+    )
+}
+
+/// Summarize the voicings shown for a chord,
+/// e.g. "Found 7 voicings (2 open, 1 barre)".
+fn voicing_summary(voicings: &[Voicing]) -> String {
+    let count = voicings.len();
+    let open = voicings.iter().filter(|v| v.contains_open_string()).count();
+    let barre = voicings.iter().filter(|v| v.has_barre()).count();
+    let plural = if count == 1 { "" } else { "s" };
+
+    format!("Found {count} voicing{plural} ({open} open, {barre} barre)")
+}
+
+/// Return the header printed above the chord charts of `chord`,
+/// optionally followed by the chord's interval formula.
+fn chart_header(chord: &Chord, show_formula: bool) -> String {
+    match show_formula {
+        true => format!("[{} ({})]", chord, chord.formula()),
+        false => format!("[{chord}]"),
+    }
+}
+
+/// Header row of the tab-separated chart output.
+const TSV_HEADER: &str = "chord\ttuning\tfrets\tfingers\tbase_fret\tbarre\topen_string\tnotes";
+
+/// Format `voicing` of `chord` as a row of tab-separated values
+/// matching `TSV_HEADER`.
+fn voicing_to_tsv(chord: &Chord, tuning: Tuning, voicing: &Voicing, chart: &ChordChart) -> String {
+    let frets = voicing.frets().join(" ");
+    let fingers = voicing.fingers_on_strings().iter().join(" ");
+    let notes = voicing.notes().join(" ");
+
+    format!(
+        "{}\t{tuning}\t{frets}\t{fingers}\t{}\t{}\t{}\t{notes}",
+        chord.name(),
+        chart.get_base_fret(),
+        voicing.has_barre(),
+        voicing.contains_open_string(),
+    )
+}
+
+/// Format `voicings` as a tablature with one line per string, labelled
+/// with the string's root note, and one column per voicing.
+fn voicings_to_tab(roots: &[Note], voicings: &[Voicing], order: TabStringOrder) -> String {
+    let root_width = roots.iter().map(|r| r.to_string().len()).max().unwrap();
+
+    // The frets of each voicing, with muted strings written as `x`.
+    let columns: Vec<Vec<String>> = voicings
+        .iter()
+        .map(|v| {
+            v.frets()
+                .zip(v.muted())
+                .map(|(f, &m)| match m {
+                    true => "x".to_string(),
+                    false => f.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut lines: Vec<String> = roots
+        .iter()
+        .enumerate()
+        .map(|(i, root)| {
+            let cells: String = columns
+                .iter()
+                .map(|column| {
+                    let width = column.iter().map(|c| c.len()).max().unwrap();
+                    format!("-{:-<width$}", column[i])
+                })
+                .collect();
+            format!("{:root_width$}|{cells}-|\n", root.to_string())
+        })
+        .collect();
+
+    if let TabStringOrder::HighFirst = order {
+        lines.reverse();
+    }
+
+    lines.concat()
+}
+
+/// A small pseudo-random number generator (SplitMix64) so that the same seed
+/// results in the same random chords on every platform.
+struct Rng(u64);
+
+impl Rng {
+    /// Create a generator seeded with `seed` or, if not given, with the current time.
+    fn new(seed: Option<u64>) -> Self {
+        let seed = seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Return a random number in `0..n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// Pick a chord with a random root and a chord type randomly chosen from `types`.
+fn random_chord(rng: &mut Rng, types: &[ChordType]) -> Chord {
+    let root = Note::from(PitchClass::C) + rng.below(PITCH_CLASS_COUNT as usize) as Semitones;
+    let chord_type = types[rng.below(types.len())];
+
+    Chord::new(root, chord_type)
+}
+
+/// Find the smallest relaxation of `config` for which `chord` can be played,
+/// trying a larger span first and a higher maximal fret (up to `frets`) second.
+/// If a `position` is given (which rules out both), suggest the closest
+/// position in which `chord` can be played instead.
+fn suggest_relaxation(
+    chord: &Chord,
+    config: VoicingConfig,
+    position: Option<FretID>,
+    frets: FretID,
+) -> Option<String> {
+    let has_voicings = |config| chord.first_voicing(config).is_some();
+
+    if let Some(position) = position {
+        return (1..=frets)
+            .sorted_by_key(|&p| (p.abs_diff(position), p))
+            .find(|&p| has_voicings(in_position(config, p, frets)))
+            .map(|p| format!("--position {p}"));
+    }
+
+    if let Some(max_span) = (config.max_span + 1..=MAX_SPAN)
+        .find(|&max_span| has_voicings(VoicingConfig { max_span, ..config }))
+    {
+        return Some(format!("--max-span {max_span}"));
+    }
+
+    if let Some(max_fret) = (config.max_fret + 1..=frets)
+        .find(|&max_fret| has_voicings(VoicingConfig { max_fret, ..config }))
+    {
+        return Some(format!("--max-fret {max_fret}"));
+    }
+
+    None
 }
 
 fn main() {
     let args = Ukebox::parse();
     let tuning = args.tuning;
+    let tuning_offset = args.tuning_offset;
+    let reverse_strings = args.reverse_strings;
+    let ascii_only = args.ascii_only;
+    let frets = args.frets;
+
+    // Create the voicing for a fret pattern given on the command line.
+    let to_voicing = |fret_pattern: FretPattern| {
+        let fret_pattern = match reverse_strings {
+            true => fret_pattern.reversed(),
+            false => fret_pattern,
+        };
+        Voicing::new(fret_pattern, tuning).with_tuning_offset(tuning_offset)
+    };
 
     match args.cmd {
         Subcommand::Chords {} => {
@@ -101,85 +812,565 @@ fn main() {
             println!("The root note C is used as an example.\n");
 
             for chord_type in ChordType::values() {
-                let symbols = chord_type.symbols().map(|s| format!("C{s}")).join(", ");
+                let symbols = chord_type
+                    .symbols()
+                    .filter(|s| !ascii_only || s.is_ascii())
+                    .map(|s| format!("C{s}"))
+                    .join(", ");
                 println!("C {chord_type} - {symbols}");
             }
         }
+        Subcommand::Tunings {} => {
+            println!("Supported tunings\n");
+
+            for tuning in Tuning::values() {
+                let roots = tuning.roots().map(|r| r.transpose(tuning_offset)).join(" ");
+                let standard = match tuning == DEFAULT_CONFIG.tuning {
+                    true => " (standard)",
+                    false => "",
+                };
+                println!("{tuning}: {roots}{standard}");
+            }
+        }
+        Subcommand::Random {
+            types,
+            count,
+            seed,
+            voicing_opts,
+            chart_opts,
+        } => {
+            let config = voicing_opts.to_config(tuning, tuning_offset, frets);
+
+            let types = match types.is_empty() {
+                true => ChordType::values().collect(),
+                false => types,
+            };
+
+            let mut rng = Rng::new(seed);
+
+            for _ in 0..count {
+                let chord = random_chord(&mut rng, &types).transpose(voicing_opts.transpose);
+                println!("{}\n", chart_header(&chord, false));
+
+                let voicing = chord.first_voicing(config);
+
+                match voicing {
+                    Some(voicing) => {
+                        let chart = chart_opts.to_chart(
+                            voicing,
+                            Some(chord.root),
+                            config.max_span,
+                            reverse_strings,
+                            ascii_only,
+                        );
+                        println!("{chart}");
+                    }
+                    None => println!("No matching chord voicing was found\n"),
+                }
+            }
+        }
+        Subcommand::Songsheet {
+            columns,
+            voicing_opts,
+            chart_opts,
+            file,
+        } => {
+            let input = read_input(&file).unwrap_or_else(|err| {
+                let msg = format!("could not read '{}': {err}", file.display());
+                Ukebox::command().error(ErrorKind::Io, msg).exit()
+            });
+
+            let config = voicing_opts.to_config(tuning, tuning_offset, frets);
+
+            let mut charts = vec![];
+
+            for name in chord_pro::chord_names(&input) {
+                let chord = match Chord::from_str(name) {
+                    Ok(chord) => chord.transpose(voicing_opts.transpose),
+                    Err(err) => {
+                        eprintln!("{name}: {err}");
+                        continue;
+                    }
+                };
+
+                match chord.first_voicing(config) {
+                    Some(voicing) => {
+                        let chart = chart_opts
+                            .to_chart(
+                                voicing,
+                                Some(chord.root),
+                                config.max_span,
+                                reverse_strings,
+                                ascii_only,
+                            )
+                            .with_title(&format!("[{}]", chord.name()));
+                        charts.push(chart);
+                    }
+                    None => eprintln!("{name}: no matching chord voicing was found"),
+                }
+            }
+
+            if charts.is_empty() {
+                println!("No chords were found");
+                return;
+            }
+
+            let rows: Vec<String> = charts
+                .chunks(columns)
+                .map(ChordChart::side_by_side)
+                .collect();
+
+            println!("{}", rows.join("\n"));
+        }
+        Subcommand::Fretboard { max_fret } => {
+            let max_fret = bounded_max_fret(max_fret, frets);
+            let frets = (0..=max_fret).map(|fret| format!("{fret:<4}")).join("");
+            println!("{}", frets.trim_end());
+
+            // Print the strings in the same order as in the chord charts.
+            // The first column contains the notes of the open strings.
+            let mut roots: Vec<Note> = tuning.roots().map(|r| r.transpose(tuning_offset)).collect();
+
+            if !reverse_strings {
+                roots.reverse();
+            }
+
+            for root in roots {
+                let notes = (0..=max_fret)
+                    .map(|fret| format!("{:<4}", (root + fret).to_string()))
+                    .join("");
+                println!("{}", notes.trim_end());
+            }
+        }
         Subcommand::Chart {
             all,
+            simplify,
+            suggest,
+            format,
+            tab_string_order,
+            near,
+            prefer_shape,
+            show_formula,
+            best,
+            sort,
+            summary,
+            columns,
             voicing_opts,
-            chord,
+            chart_opts,
+            notes,
+            chords,
         } => {
-            let chord = chord.transpose(voicing_opts.transpose);
+            let config = voicing_opts.to_config(tuning, tuning_offset, frets);
 
-            let config = VoicingConfig {
-                tuning,
-                min_fret: voicing_opts.min_fret,
-                max_fret: voicing_opts.max_fret,
-                max_span: voicing_opts.max_span,
+            let chords = match notes {
+                Some(notes) => vec![chord_from_notes(&notes)],
+                None => chords,
             };
 
-            let mut voicings = chord.voicings(config).peekable();
-
-            if voicings.peek().is_none() {
-                println!("No matching chord voicing was found");
-            } else {
-                println!("[{chord}]\n");
+            if let ChartFormat::Tsv = format {
+                println!("{TSV_HEADER}");
             }
 
-            for voicing in voicings {
-                let chart = ChordChart::new(voicing, voicing_opts.max_span);
-                println!("{chart}");
+            // Voicings to be shown side by side in the tablature.
+            let mut tab_voicings = vec![];
+
+            for chord in chords.iter() {
+                let chord = chord.transpose(voicing_opts.transpose);
+
+                let chord = match simplify && chord.first_voicing(config).is_none() {
+                    true => chord.simplify(),
+                    false => chord,
+                };
+
+                let voicings: Vec<Voicing> = match (near, prefer_shape) {
+                    (Some(fret_pattern), _) => {
+                        let target = to_voicing(fret_pattern);
+                        chord.closest_voicing(config, &target).into_iter().collect()
+                    }
+                    (None, Some(shape)) => chord.voicings_by_shape(config, shape).collect(),
+                    (None, None) if best => chord.best_voicing(config).into_iter().collect(),
+                    // Only the first voicing is shown, so there is no need to sort them all.
+                    (None, None) if !all && matches!(sort, VoicingOrder::Fret) => {
+                        chord.first_voicing(config).into_iter().collect()
+                    }
+                    (None, None) => match sort {
+                        VoicingOrder::Fret => chord.voicings(config).collect(),
+                        VoicingOrder::Pitch => chord
+                            .voicings(config)
+                            .sorted_by(|v1, v2| v1.cmp_by_pitch(v2))
+                            .collect(),
+                    },
+                };
+
+                let mut voicings = voicings.into_iter().peekable();
+
+                let count = match all {
+                    true => usize::MAX,
+                    false => 1,
+                };
+
+                if let ChartFormat::Tsv = format {
+                    for voicing in voicings.take(count) {
+                        let chart = chart_opts.to_chart(
+                            voicing,
+                            Some(chord.root),
+                            config.max_span,
+                            reverse_strings,
+                            ascii_only,
+                        );
+                        println!("{}", voicing_to_tsv(&chord, tuning, &voicing, &chart));
+                    }
+                    continue;
+                }
+
+                if let ChartFormat::Tab = format {
+                    tab_voicings.extend(voicings.take(count));
+                    continue;
+                }
+
+                if voicings.peek().is_none() {
+                    // Make clear which chord is meant if there are several.
+                    if chords.len() > 1 {
+                        println!("{}\n", chart_header(&chord, show_formula));
+                    }
+                    println!("No matching chord voicing was found");
+
+                    if suggest {
+                        if let Some(suggestion) =
+                            suggest_relaxation(&chord, config, voicing_opts.position, frets)
+                        {
+                            println!("Try {suggestion}");
+                        }
+                    }
+                } else {
+                    println!("{}\n", chart_header(&chord, show_formula));
+                }
+
+                let voicings: Vec<Voicing> = voicings.take(count).collect();
+
+                if columns > 1 {
+                    let charts: Vec<ChordChart> = voicings
+                        .iter()
+                        .map(|v| {
+                            chart_opts.to_chart(
+                                *v,
+                                Some(chord.root),
+                                config.max_span,
+                                reverse_strings,
+                                ascii_only,
+                            )
+                        })
+                        .collect();
+
+                    for row in charts.chunks(columns) {
+                        println!("{}", ChordChart::side_by_side(row));
+                    }
+                } else {
+                    for voicing in voicings.iter() {
+                        let doubled = voicing.doubled_notes();
+
+                        if config.prefer_root_doubling && !doubled.is_empty() {
+                            println!("Doubled: {}\n", doubled.iter().join(", "));
+                        }
+
+                        let chart = chart_opts.to_chart(
+                            *voicing,
+                            Some(chord.root),
+                            config.max_span,
+                            reverse_strings,
+                            ascii_only,
+                        );
+                        println!("{chart}");
+                    }
+                }
 
-                if !all {
-                    break;
+                if summary && !voicings.is_empty() {
+                    println!("{}\n", voicing_summary(&voicings));
                 }
             }
+
+            if let ChartFormat::Tab = format {
+                if tab_voicings.is_empty() {
+                    println!("No matching chord voicing was found");
+                    return;
+                }
+
+                let roots: Vec<Note> = tuning.roots().map(|r| r.transpose(tuning_offset)).collect();
+                print!(
+                    "{}",
+                    voicings_to_tab(&roots, &tab_voicings, tab_string_order)
+                );
+            }
         }
-        Subcommand::Name { fret_pattern } => {
-            let voicing = Voicing::new(fret_pattern, tuning);
-            let chords = voicing.get_chords();
+        Subcommand::Name {
+            all,
+            format,
+            verbose,
+            no_duplicate_names,
+            accidentals,
+            file,
+            transpose,
+            fret_pattern,
+        } => {
+            // The explanation is only part of the text output.
+            if verbose && matches!(format, OutputFormat::Json) {
+                let msg = "--verbose cannot be used with --format json";
+                Ukebox::command()
+                    .error(ErrorKind::ArgumentConflict, msg)
+                    .exit();
+            }
+
+            // Transposing the chord is the same as tuning all strings up or down.
+            let to_voicing = |fret_pattern| to_voicing(fret_pattern).with_tuning_offset(transpose);
+
+            let get_chords = |voicing: &Voicing| {
+                let chords = voicing
+                    .get_chords()
+                    .iter()
+                    .map(|chord| chord.spelled_with(accidentals))
+                    .collect();
+
+                match no_duplicate_names {
+                    true => without_duplicate_names(chords),
+                    false => chords,
+                }
+            };
+
+            if let Some(path) = file {
+                let input = read_input(&path).unwrap_or_else(|err| {
+                    let msg = format!("could not read '{}': {err}", path.display());
+                    Ukebox::command().error(ErrorKind::Io, msg).exit()
+                });
+
+                let mut json_results = vec![];
+
+                for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+                    let fret_pattern = match FretPattern::from_str(line) {
+                        Ok(fret_pattern) => fret_pattern,
+                        Err(err) => {
+                            eprintln!("{line}: {err}");
+                            continue;
+                        }
+                    };
+
+                    let voicing = to_voicing(fret_pattern);
+                    let chords = get_chords(&voicing);
+
+                    match format {
+                        OutputFormat::Text => {
+                            for name in chord_names(&voicing, &chords, accidentals) {
+                                println!("{line}: {name}");
+                            }
+                        }
+                        OutputFormat::Json => {
+                            let mut json = chords_to_json(&voicing, tuning, &chords);
+                            json["input"] = json!(line);
+                            json_results.push(json);
+                        }
+                    }
+                }
+
+                if let OutputFormat::Json = format {
+                    println!("{:#}", json!(json_results));
+                }
+                return;
+            }
+
+            // The fret pattern is required unless a file is given.
+            let voicing = to_voicing(fret_pattern.unwrap());
+            let chords = get_chords(&voicing);
+
+            if verbose {
+                println!("{}", explain_chord_detection(&voicing));
+            }
+
+            if let OutputFormat::Json = format {
+                let json = chords_to_json(&voicing, tuning, &chords);
+                println!("{json:#}");
+                return;
+            }
+
+            if !all {
+                for name in chord_names(&voicing, &chords, accidentals) {
+                    println!("{name}");
+                }
+                return;
+            }
 
             if chords.is_empty() {
-                println!("No matching chord was found");
+                println!("{}", chord_names(&voicing, &chords, accidentals).join("\n"));
             }
 
+            let root_width = voicing.roots().map(|r| r.to_string().len()).max().unwrap();
+
             for chord in chords {
-                println!("{chord}");
+                println!("[{chord}]\n");
+
+                // Label the strings with the notes as spelled in the chord.
+                let voicing = voicing.respell(&chord);
+
+                let mut strings: Vec<_> = voicing.uke_strings().zip(voicing.muted()).collect();
+
+                // Print the strings in the same order as in the chord charts.
+                if !reverse_strings {
+                    strings.reverse();
+                }
+
+                for ((root, _fret, note), &muted) in strings {
+                    let root = root.to_string();
+
+                    if muted {
+                        println!("{root:root_width$}  x");
+                        continue;
+                    }
+
+                    // Find the interval of the chord tone played on the string.
+                    let (_note, interval) = chord
+                        .notes
+                        .iter()
+                        .zip(chord.intervals.iter())
+                        .find(|(n, _i)| *n == note)
+                        .unwrap();
+                    let note = note.to_string();
+                    println!("{root:root_width$}  {note:2}  {interval}");
+                }
+
+                println!();
+            }
+        }
+        Subcommand::Diff {
+            fret_pattern1,
+            fret_pattern2,
+            chart_opts,
+        } => {
+            let voicing1 = to_voicing(fret_pattern1);
+            let voicing2 = to_voicing(fret_pattern2);
+
+            // Use the same width for both charts to make them easier to compare.
+            let width = max(voicing1.get_span(), voicing2.get_span());
+
+            for voicing in [voicing1, voicing2] {
+                // Highlight the root of the (first) chord found for the voicing.
+                let root = voicing.get_chords().first().map(|c| c.root);
+                let chart = chart_opts.to_chart(voicing, root, width, reverse_strings, ascii_only);
+                println!("{chart}");
+            }
+
+            let dist = voicing1.distance(voicing2);
+
+            println!(
+                "Semitone distance: {}, fingering distance: {}",
+                dist.semitone_distance(),
+                dist.fingering_distance()
+            );
+        }
+        Subcommand::Revoice {
+            from,
+            to,
+            voicing_opts,
+            chart_opts,
+            fret_pattern,
+        } => {
+            let fret_pattern = match reverse_strings {
+                true => fret_pattern.reversed(),
+                false => fret_pattern,
+            };
+            let voicing = Voicing::new(fret_pattern, from.unwrap_or(tuning))
+                .with_tuning_offset(tuning_offset);
+
+            // All the chords found consist of the same notes, so the simplest one is charted.
+            let chord = match voicing.get_chords().first() {
+                Some(chord) => chord.transpose(voicing_opts.transpose),
+                None => {
+                    println!("No matching chord was found");
+                    return;
+                }
+            };
+
+            let config = voicing_opts.to_config(to, tuning_offset, frets);
+
+            println!("{}\n", chart_header(&chord, false));
+
+            match chord.first_voicing(config) {
+                Some(voicing) => {
+                    let chart = chart_opts.to_chart(
+                        voicing,
+                        Some(chord.root),
+                        config.max_span,
+                        reverse_strings,
+                        ascii_only,
+                    );
+                    println!("{chart}");
+                }
+                None => println!("No matching chord voicing was found"),
             }
         }
         Subcommand::VoiceLead {
+            common_tones,
+            explain,
+            beam_width,
+            max_jump,
             voicing_opts,
+            chart_opts,
             chord_seq,
         } => {
             let chord_seq = chord_seq.transpose(voicing_opts.transpose);
 
-            let config = VoicingConfig {
-                tuning,
-                min_fret: voicing_opts.min_fret,
-                max_fret: voicing_opts.max_fret,
-                max_span: voicing_opts.max_span,
-            };
+            let config = voicing_opts.to_config(tuning, tuning_offset, frets);
 
-            let mut voicing_graph = VoicingGraph::new(config);
+            let mut voicing_graph = VoicingGraph::new(config)
+                .with_common_tones(common_tones)
+                .with_beam_width(beam_width)
+                .with_max_dist(max_jump);
             voicing_graph.add(&chord_seq);
 
             let mut path_found = false;
 
             for (path, _dist) in voicing_graph.paths(1) {
+                // Render each voicing as a chord chart, just like the chart subcommand.
                 for (chord, voicing) in chord_seq.chords().zip(path.iter()) {
-                    println!("[{chord}]\n");
-                    let chart = ChordChart::new(*voicing, voicing_opts.max_span);
+                    println!("{}\n", chart_header(chord, false));
+                    let chart = chart_opts.to_chart(
+                        *voicing,
+                        Some(chord.root),
+                        config.max_span,
+                        reverse_strings,
+                        ascii_only,
+                    );
                     println!("{chart}");
                 }
-                //println!("{:?}\n", dist);
-                //println!("---------------------------\n");
+
+                if explain {
+                    let chords: Vec<_> = chord_seq.chords().collect();
+                    let mut total = Distance::default();
+
+                    for ((c1, v1), (c2, v2)) in chords.iter().zip(path.iter()).tuple_windows() {
+                        let dist = v1.distance(*v2);
+                        println!(
+                            "{} -> {}: {}",
+                            c1.name(),
+                            c2.name(),
+                            describe_distance(dist)
+                        );
+                        total = total + dist;
+                    }
+
+                    println!("Total: {}\n", describe_distance(total));
+                }
 
                 path_found = true;
             }
 
             if !path_found {
                 println!("No matching chord voicing sequence was found");
+
+                // If all the chords can be played, the voicings are too far apart.
+                if chord_seq
+                    .chords()
+                    .all(|chord| chord.first_voicing(config).is_some())
+                {
+                    println!("Try a larger --max-jump (currently {max_jump})");
+                }
             }
         }
     }
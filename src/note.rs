@@ -1,10 +1,14 @@
 use std::{
+    cmp::Ordering,
     fmt,
+    hash::{Hash, Hasher},
     ops::{Add, Sub},
     str::FromStr,
 };
 
-use crate::{Interval, PitchClass, Semitones, StaffPosition};
+use crate::{
+    Accidental, Interval, Octave, PitchClass, Semitones, StaffPosition, PITCH_CLASS_COUNT,
+};
 
 /// Custom error for strings that cannot be parsed into notes.
 #[derive(Debug, thiserror::Error)]
@@ -14,7 +18,12 @@ pub struct ParseNoteError {
 }
 
 /// A note such a C, C# and so on.
-#[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord)]
+///
+/// Notes are compared by their pitch class only: `==`, the ordering and the
+/// hash all ignore how a note is spelled, so `C#` and `Db` are equal and
+/// neither sorts before the other. Use `same_pitch` and `same_spelling` to
+/// make the intended comparison explicit.
+#[derive(Debug, Clone, Copy)]
 pub struct Note {
     pub pitch_class: PitchClass,
     staff_position: StaffPosition,
@@ -28,6 +37,108 @@ impl Note {
         }
     }
 
+    /// Return `true` if both notes have the same pitch class, no matter how
+    /// they are spelled, e.g. for `C#` and `Db`. This is the same as `==`.
+    pub fn same_pitch(&self, other: &Self) -> bool {
+        self.pitch_class == other.pitch_class
+    }
+
+    /// Return `true` if both notes are spelled the same, i.e. they have the same
+    /// pitch class and staff position, e.g. for `C#` and `C#` but not for `C#` and `Db`.
+    pub fn same_spelling(&self, other: &Self) -> bool {
+        self.pitch_class == other.pitch_class && self.staff_position == other.staff_position
+    }
+
+    /// Return the note that is `semitones` higher (or lower if negative)
+    /// than this note. Notes that can be sharp or flat are spelled as sharps
+    /// in both directions, e.g. `F#` for `G` transposed down by one semitone,
+    /// so that tuned-down open strings are spelled like the other notes.
+    pub fn transpose(self, semitones: i8) -> Self {
+        self + semitones.rem_euclid(PITCH_CLASS_COUNT as i8) as Semitones
+    }
+
+    /// Parse a syllable of fixed-do solfège (where `do` is always C) into a note,
+    /// e.g. `sol` into G. Chromatic syllables are spelled as sharps when raising
+    /// (`di`, `ri`, `fi`, `si`, `li`) and as flats when lowering (`ra`, `me`, `se`,
+    /// `le`, `te`) a syllable. Letter case is ignored.
+    pub fn from_solfege(s: &str) -> Result<Self, ParseNoteError> {
+        let name = match s.to_lowercase().as_str() {
+            "do" => "C",
+            "di" => "C#",
+            "ra" => "Db",
+            "re" => "D",
+            "ri" => "D#",
+            "me" => "Eb",
+            "mi" => "E",
+            "fa" => "F",
+            "fi" => "F#",
+            "se" => "Gb",
+            "sol" | "so" => "G",
+            "si" => "G#",
+            "le" => "Ab",
+            "la" => "A",
+            "li" => "A#",
+            "te" => "Bb",
+            "ti" => "B",
+            _ => {
+                return Err(ParseNoteError {
+                    name: s.to_string(),
+                })
+            }
+        };
+
+        Self::from_str(name)
+    }
+
+    /// Return the note for a MIDI note number together with the octave it
+    /// sounds in, e.g. `(C, 4)` for 60 (middle C) and `(A, 4)` for 69.
+    /// Notes that can be sharp or flat are spelled as sharps. The lowest
+    /// MIDI notes 0 to 11 are in octave -1.
+    /// Return `None` for numbers outside of the MIDI range 0 to 127.
+    pub fn from_midi(n: u8) -> Option<(Self, Octave)> {
+        match n {
+            0..=127 => {
+                let note = Self::from(PitchClass::from(n % PITCH_CLASS_COUNT));
+                let octave = (n / PITCH_CLASS_COUNT) as Octave - 1;
+                Some((note, octave))
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the note spelled with the given accidental if it is not a white
+    /// note, e.g. `Db` for `C#` and `Accidental::Flat`. White notes are always
+    /// spelled without accidentals, e.g. `C` for `B#`.
+    pub fn spelled_with(self, accidental: Accidental) -> Self {
+        let note = Self::from(self.pitch_class);
+
+        match (self.is_white_note(), accidental) {
+            (false, Accidental::Flat) => Self::new(self.pitch_class, note.staff_position + 1),
+            _ => note,
+        }
+    }
+
+    /// Return the staff position on which the note is written.
+    pub fn staff_position(&self) -> StaffPosition {
+        self.staff_position
+    }
+
+    /// Return the letter of the staff position on which the note is written,
+    /// i.e. the note name without accidentals, e.g. `D` for `Db` and `C` for `C#`.
+    pub fn letter(&self) -> char {
+        use StaffPosition::*;
+
+        match self.staff_position {
+            CPos => 'C',
+            DPos => 'D',
+            EPos => 'E',
+            FPos => 'F',
+            GPos => 'G',
+            APos => 'A',
+            BPos => 'B',
+        }
+    }
+
     /// Return `true` if this note is a "white note", i.e. a note represented
     /// by a white key on the piano (i.e. the note is part of the C major scale).
     pub fn is_white_note(&self) -> bool {
@@ -35,13 +146,73 @@ impl Note {
 
         matches!(self.pitch_class, C | D | E | F | G | A | B)
     }
+
+    /// Return all notes that have the same pitch class as this note but are
+    /// written on a different staff position, e.g. `Db` for `C#`.
+    /// Notes are considered up to double sharps and double flats.
+    pub fn enharmonic_equivalents(&self) -> Vec<Self> {
+        use StaffPosition::*;
+
+        [CPos, DPos, EPos, FPos, GPos, APos, BPos]
+            .iter()
+            .filter(|&&sp| sp != self.staff_position)
+            .filter(|&&sp| {
+                // Number of semitones by which the white note on the staff
+                // position has to be raised to get our pitch class.
+                let diff = self.pitch_class - Self::white_pitch_class(sp);
+                matches!(diff, 0 | 1 | 2 | 10 | 11)
+            })
+            .map(|&sp| Self::new(self.pitch_class, sp))
+            .collect()
+    }
+
+    /// Return the pitch class of the white note on the given staff position.
+    fn white_pitch_class(staff_position: StaffPosition) -> PitchClass {
+        use PitchClass::*;
+        use StaffPosition::*;
+
+        match staff_position {
+            CPos => C,
+            DPos => D,
+            EPos => E,
+            FPos => F,
+            GPos => G,
+            APos => A,
+            BPos => B,
+        }
+    }
 }
 
 impl PartialEq for Note {
     /// Treat two notes as equal if they have the same pitch class.
     /// For example, `B sharp`, `C` and `D double flat` should all match.
     fn eq(&self, other: &Self) -> bool {
-        self.pitch_class == other.pitch_class
+        self.same_pitch(other)
+    }
+}
+
+impl Eq for Note {}
+
+impl PartialOrd for Note {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Note {
+    /// Order notes by pitch class only to be consistent with `PartialEq`,
+    /// e.g. `C#` and `Db` are neither smaller nor greater than each other,
+    /// so sorting keeps enharmonic equivalents in their original order.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.pitch_class.cmp(&other.pitch_class)
+    }
+}
+
+impl Hash for Note {
+    /// Hash only the pitch class to be consistent with `PartialEq`,
+    /// e.g. `C#` and `Db` have the same hash.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pitch_class.hash(state);
     }
 }
 
@@ -209,9 +380,12 @@ impl Sub<Semitones> for Note {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use rstest::rstest;
     use Interval::*;
     use PitchClass::*;
+    use StaffPosition::*;
 
     use super::*;
 
@@ -240,6 +414,77 @@ mod tests {
         assert_eq!(format!("{note}"), s);
     }
 
+    #[rstest(
+        note,
+        sharp,
+        flat,
+        case("C", "C", "C"),
+        case("C#", "C#", "Db"),
+        case("Db", "C#", "Db"),
+        case("F#", "F#", "Gb"),
+        case("Bb", "A#", "Bb"),
+        case("B", "B", "B")
+    )]
+    fn test_spelled_with(note: Note, sharp: &str, flat: &str) {
+        assert_eq!(note.spelled_with(Accidental::Sharp).to_string(), sharp);
+        assert_eq!(note.spelled_with(Accidental::Flat).to_string(), flat);
+    }
+
+    #[rstest(
+        syllable,
+        note,
+        case("do", "C"),
+        case("di", "C#"),
+        case("ra", "Db"),
+        case("re", "D"),
+        case("me", "Eb"),
+        case("fa", "F"),
+        case("fi", "F#"),
+        case("sol", "G"),
+        case("so", "G"),
+        case("le", "Ab"),
+        case("la", "A"),
+        case("te", "Bb"),
+        case("ti", "B"),
+        case("Do", "C"),
+        case("SOL", "G")
+    )]
+    fn test_from_solfege(syllable: &str, note: &str) {
+        let n = Note::from_solfege(syllable).unwrap();
+        assert_eq!(n.to_string(), note);
+    }
+
+    #[rstest(syllable, case(""), case("C"), case("dol"), case("ut"))]
+    fn test_from_solfege_error(syllable: &str) {
+        assert!(Note::from_solfege(syllable).is_err());
+    }
+
+    #[rstest(
+        n,
+        note,
+        octave,
+        case(0, "C", -1),
+        case(11, "B", -1),
+        case(12, "C", 0),
+        case(59, "B", 3),
+        case(60, "C", 4),
+        case(61, "C#", 4),
+        case(67, "G", 4),
+        case(69, "A", 4),
+        case(70, "A#", 4),
+        case(127, "G", 9)
+    )]
+    fn test_from_midi(n: u8, note: &str, octave: Octave) {
+        let (m, o) = Note::from_midi(n).unwrap();
+        assert_eq!(m.to_string(), note);
+        assert_eq!(o, octave);
+    }
+
+    #[rstest(n, case(128), case(255))]
+    fn test_from_midi_out_of_range(n: u8) {
+        assert!(Note::from_midi(n).is_none());
+    }
+
     #[rstest(
         note,
         is_white_note,
@@ -265,6 +510,23 @@ mod tests {
         assert_eq!(note.is_white_note(), is_white_note);
     }
 
+    #[rstest(
+        note,
+        staff_position,
+        letter,
+        case("C", CPos, 'C'),
+        case("C#", CPos, 'C'),
+        case("Db", DPos, 'D'),
+        case("F#", FPos, 'F'),
+        case("Gb", GPos, 'G'),
+        case("Bb", BPos, 'B'),
+        case("B", BPos, 'B')
+    )]
+    fn test_staff_position_and_letter(note: Note, staff_position: StaffPosition, letter: char) {
+        assert_eq!(note.staff_position(), staff_position);
+        assert_eq!(note.letter(), letter);
+    }
+
     #[rstest(
         pitch_class,
         note,
@@ -294,10 +556,16 @@ mod tests {
         case("C", MajorThird, "E"),
         case("C", PerfectFifth, "G"),
         case("C#", PerfectUnison, "C#"),
-        case("C#", MajorThird, "F")
+        case("C#", MajorThird, "F"),
+        case("C", AugmentedFourth, "F#"),
+        case("C", DiminishedFifth, "Gb"),
+        case("C", MinorSixth, "Ab"),
+        case("C", AugmentedFifth, "G#")
     )]
     fn test_add_interval(note1: Note, interval: Interval, note2: Note) {
         assert_eq!(note1 + interval, note2);
+        // Make sure the resulting note is also spelled correctly.
+        assert_eq!((note1 + interval).to_string(), note2.to_string());
     }
 
     #[rstest(
@@ -339,4 +607,114 @@ mod tests {
     fn test_subtract_semitones(note1: Note, n: Semitones, note2: Note) {
         assert_eq!(note1 - n, note2);
     }
+
+    #[rstest(
+        note1,
+        n,
+        note2,
+        case("C", 0, "C"),
+        case("C", 1, "C#"),
+        case("C", -1, "B"),
+        case("D", -1, "C#"),
+        case("G", -1, "F#"),
+        case("Db", -1, "C"),
+        case("A", 14, "B"),
+        case("A", -12, "A")
+    )]
+    fn test_transpose(note1: Note, n: i8, note2: Note) {
+        let note = note1.transpose(n);
+        assert_eq!(note, note2);
+        assert_eq!(note.to_string(), note2.to_string());
+    }
+
+    #[rstest(
+        note,
+        staff_positions,
+        case("C", vec![DPos, BPos]),            // Dbb, B#
+        case("C#", vec![DPos, BPos]),           // Db, Bx
+        case("Db", vec![CPos, BPos]),           // C#, Bx
+        case("D", vec![CPos, EPos]),            // Cx, Ebb
+        case("E", vec![DPos, FPos]),            // Dx, Fb
+        case("G#", vec![APos]),                 // Ab
+        case("B", vec![CPos, APos]),            // Cb, Ax
+    )]
+    fn test_enharmonic_equivalents(note: Note, staff_positions: Vec<StaffPosition>) {
+        let equivalents = note.enharmonic_equivalents();
+
+        assert!(equivalents.iter().all(|n| *n == note));
+        assert_eq!(
+            equivalents
+                .iter()
+                .map(|n| n.staff_position)
+                .collect::<Vec<_>>(),
+            staff_positions
+        );
+    }
+
+    #[rstest(
+        notes,
+        count,
+        case(vec![Note::new(C, CPos), Note::new(D, DPos)], 2),
+        // C# and Db
+        case(vec![Note::new(CSharp, CPos), Note::new(CSharp, DPos)], 1),
+        // B# and C
+        case(vec![Note::new(C, BPos), Note::new(C, CPos)], 1),
+        // C, B#, Dbb, C# and Db
+        case(
+            vec![
+                Note::new(C, CPos),
+                Note::new(C, BPos),
+                Note::new(C, DPos),
+                Note::new(CSharp, CPos),
+                Note::new(CSharp, DPos),
+            ],
+            2
+        ),
+    )]
+    fn test_hash(notes: Vec<Note>, count: usize) {
+        let set: HashSet<Note> = notes.into_iter().collect();
+        assert_eq!(set.len(), count);
+    }
+
+    #[rstest(
+        note1,
+        note2,
+        same_pitch,
+        same_spelling,
+        case("C#", "C#", true, true),
+        case("C#", "Db", true, false),
+        case("Db", "C#", true, false),
+        case("A#", "Bb", true, false),
+        case("C", "D", false, false),
+        case("C#", "D", false, false)
+    )]
+    fn test_same_pitch_and_spelling(
+        note1: Note,
+        note2: Note,
+        same_pitch: bool,
+        same_spelling: bool,
+    ) {
+        assert_eq!(note1.same_pitch(&note2), same_pitch);
+        assert_eq!(note1.same_spelling(&note2), same_spelling);
+        assert_eq!(note1 == note2, same_pitch);
+        assert_eq!(note1.to_string() == note2.to_string(), same_spelling);
+        assert_eq!(note1.cmp(&note2) == Ordering::Equal, same_pitch);
+    }
+
+    #[test]
+    fn test_sort_keeps_enharmonic_order() {
+        let notes: Vec<Note> = ["Db", "C#", "C", "Db"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let mut sorted = notes.clone();
+        sorted.sort();
+
+        // C# and Db are equal, so they stay in their original order.
+        assert_eq!(
+            sorted.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+            ["C", "Db", "C#", "Db"]
+        );
+        assert!(sorted.iter().all(|n| notes.contains(n)));
+    }
 }
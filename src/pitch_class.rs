@@ -12,7 +12,7 @@ use crate::{Semitones, PITCH_CLASS_COUNT};
 /// higher octave.
 /// For example, pitch class 12 is the same as pitch class 0 and corresponds
 /// to the pitch class of C.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PitchClass {
     C,
     CSharp,
@@ -28,6 +28,35 @@ pub enum PitchClass {
     B,
 }
 
+impl PitchClass {
+    /// Iterator over all twelve pitch classes in ascending order,
+    /// starting with `C`.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        (0..PITCH_CLASS_COUNT).map(Self::from)
+    }
+
+    /// Return the common names of the pitch class, i.e. all of its
+    /// spellings using at most one sharp or flat.
+    pub fn names(&self) -> Vec<&'static str> {
+        use PitchClass::*;
+
+        match self {
+            C => vec!["C", "B#"],
+            CSharp => vec!["C#", "Db"],
+            D => vec!["D"],
+            DSharp => vec!["D#", "Eb"],
+            E => vec!["E", "Fb"],
+            F => vec!["F", "E#"],
+            FSharp => vec!["F#", "Gb"],
+            G => vec!["G"],
+            GSharp => vec!["G#", "Ab"],
+            A => vec!["A"],
+            ASharp => vec!["A#", "Bb"],
+            B => vec!["B", "Cb"],
+        }
+    }
+}
+
 impl From<Semitones> for PitchClass {
     /// Convert an integer into a pitch class.
     ///
@@ -85,7 +114,7 @@ impl Sub for PitchClass {
     ///
     /// Examples:
     /// * D - C: both pitch classes are assumed to be in the same octave, D being
-    ///          higher than C. The difference is 2.
+    ///   higher than C. The difference is 2.
     /// * D - A: D is higher than A, the difference is 5.
     fn sub(self, other: Self) -> Semitones {
         let d = self as i8 - other as i8;
@@ -111,6 +140,8 @@ impl Sub<Semitones> for PitchClass {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use rstest::rstest;
     use PitchClass::*;
 
@@ -141,6 +172,57 @@ mod tests {
         assert_eq!(PitchClass::from(n), pitch_class);
     }
 
+    #[test]
+    fn test_iter() {
+        let pitch_classes: Vec<_> = PitchClass::iter().collect();
+        assert_eq!(
+            pitch_classes,
+            vec![C, CSharp, D, DSharp, E, F, FSharp, G, GSharp, A, ASharp, B]
+        );
+    }
+
+    #[rstest(
+        pc1,
+        pc2,
+        case(C, CSharp),
+        case(CSharp, D),
+        case(E, F),
+        case(ASharp, B),
+        case(C, B)
+    )]
+    fn test_ordering(pc1: PitchClass, pc2: PitchClass) {
+        assert!(pc1 < pc2);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut pitch_classes = vec![G, C, B, E, C];
+        pitch_classes.sort();
+        pitch_classes.dedup();
+        assert_eq!(pitch_classes, vec![C, E, G, B]);
+    }
+
+    #[rstest(
+        pitch_class,
+        names,
+        case(C, vec!["C", "B#"]),
+        case(CSharp, vec!["C#", "Db"]),
+        case(D, vec!["D"]),
+        case(F, vec!["F", "E#"]),
+        case(B, vec!["B", "Cb"])
+    )]
+    fn test_names(pitch_class: PitchClass, names: Vec<&str>) {
+        assert_eq!(pitch_class.names(), names);
+    }
+
+    #[test]
+    fn test_hash() {
+        let counts = PitchClass::iter()
+            .map(|pc| (pc, 0))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(counts.len(), PITCH_CLASS_COUNT as usize);
+    }
+
     #[rstest(
         pitch_class,
         n,
@@ -0,0 +1,54 @@
+use std::fmt;
+
+use crate::Interval;
+
+/// A coarse classification of chords that groups chord types by the
+/// quality of their third, fifth and seventh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Quality {
+    Major,
+    Minor,
+    Dominant,
+    Diminished,
+    Augmented,
+    Suspended,
+    Power,
+}
+
+impl Quality {
+    /// Determine the quality of a chord consisting of the given intervals
+    /// by looking at its third, fifth and seventh.
+    pub fn from_intervals(intervals: &[Interval]) -> Self {
+        use Interval::*;
+
+        let has = |interval| intervals.contains(&interval);
+
+        match (has(MajorThird), has(MinorThird)) {
+            (true, _) if has(AugmentedFifth) => Self::Augmented,
+            (true, _) if has(MinorSeventh) => Self::Dominant,
+            (true, _) => Self::Major,
+            (_, true) if has(DiminishedFifth) => Self::Diminished,
+            (_, true) => Self::Minor,
+            _ if has(PerfectFourth) || has(MajorSecond) => Self::Suspended,
+            _ => Self::Power,
+        }
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Quality::*;
+
+        let s = match self {
+            Major => "major",
+            Minor => "minor",
+            Dominant => "dominant",
+            Diminished => "diminished",
+            Augmented => "augmented",
+            Suspended => "suspended",
+            Power => "power",
+        };
+
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use clap::ValueEnum;
+
+use crate::{FretID, Note, PitchClass, Tuning, STRING_COUNT};
+
+/// A moveable chord shape, named after the open major chord (in C tuning)
+/// that it is based on. Similar to the CAGED system on the guitar, such a
+/// shape can be moved up the neck to play any other major chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum)]
+#[clap(rename_all = "UPPER")]
+pub enum Shape {
+    C,
+    A,
+    G,
+    F,
+    D,
+}
+
+impl Shape {
+    /// Iterator over the values of the Shape enum.
+    pub fn values() -> impl Iterator<Item = Self> {
+        [Self::C, Self::A, Self::G, Self::F, Self::D]
+            .iter()
+            .copied()
+    }
+
+    /// Return the frets of the open chord that the shape is based on.
+    pub fn frets(self) -> [FretID; STRING_COUNT] {
+        match self {
+            Self::C => [0, 0, 0, 3],
+            Self::A => [2, 1, 0, 0],
+            Self::G => [0, 2, 3, 2],
+            Self::F => [2, 0, 1, 0],
+            Self::D => [2, 2, 2, 0],
+        }
+    }
+
+    /// Return the root of the open chord that the shape is based on
+    /// (in C tuning).
+    fn root(self) -> PitchClass {
+        match self {
+            Self::C => PitchClass::C,
+            Self::A => PitchClass::A,
+            Self::G => PitchClass::G,
+            Self::F => PitchClass::F,
+            Self::D => PitchClass::D,
+        }
+    }
+
+    /// Return the frets of the shape moved up the neck so that it plays the
+    /// major chord with root `root` in the given tuning, e.g. 5433 for the
+    /// A shape and root C in C tuning. The open strings of the shape are
+    /// replaced by a barre.
+    pub fn moved_to(self, root: Note, tuning: Tuning, tuning_offset: i8) -> [FretID; STRING_COUNT] {
+        let shape_root = Note::from(self.root() + tuning.get_semitones()).transpose(tuning_offset);
+        let shift = root.pitch_class - shape_root.pitch_class;
+
+        self.frets().map(|f| f + shift)
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::C => "C",
+            Self::A => "A",
+            Self::G => "G",
+            Self::F => "F",
+            Self::D => "D",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_values() {
+        let shapes: Vec<_> = Shape::values().collect();
+        assert_eq!(shapes, Shape::value_variants());
+    }
+
+    #[rstest(
+        shape,
+        root,
+        tuning,
+        tuning_offset,
+        frets,
+        case(Shape::C, "C", Tuning::C, 0, [0, 0, 0, 3]),
+        case(Shape::A, "A", Tuning::C, 0, [2, 1, 0, 0]),
+        case(Shape::A, "C", Tuning::C, 0, [5, 4, 3, 3]),
+        case(Shape::G, "C", Tuning::C, 0, [5, 7, 8, 7]),
+        case(Shape::F, "C", Tuning::C, 0, [9, 7, 8, 7]),
+        case(Shape::D, "C", Tuning::C, 0, [12, 12, 12, 10]),
+        case(Shape::C, "D", Tuning::C, 0, [2, 2, 2, 5]),
+        case(Shape::C, "D", Tuning::D, 0, [0, 0, 0, 3]),
+        case(Shape::A, "C", Tuning::D, 0, [3, 2, 1, 1]),
+        case(Shape::C, "B", Tuning::C, -1, [0, 0, 0, 3]),
+        case(Shape::C, "C", Tuning::C, -1, [1, 1, 1, 4])
+    )]
+    fn test_moved_to(
+        shape: Shape,
+        root: &str,
+        tuning: Tuning,
+        tuning_offset: i8,
+        frets: [FretID; STRING_COUNT],
+    ) {
+        let root = Note::from_str(root).unwrap();
+        assert_eq!(shape.moved_to(root, tuning, tuning_offset), frets);
+    }
+}
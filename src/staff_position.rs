@@ -13,7 +13,7 @@ const STAFF_POSITION_COUNT: StaffSteps = 7;
 /// (if its staff position is `FPos` or `Gb` (if its staff position is `GPos`).
 ///
 /// https://en.wikipedia.org/wiki/Staff_(music)#Staff_positions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum StaffPosition {
     CPos,
     DPos,
@@ -88,7 +88,11 @@ mod tests {
         case(DPos, 2, FPos),
         case(CPos, 7, CPos),
         case(CPos, 8, DPos),
-        case(CPos, 14, CPos)
+        case(CPos, 14, CPos),
+        case(BPos, 1, CPos),
+        case(GPos, 3, CPos),
+        case(APos, 4, EPos),
+        case(BPos, 13, APos)
     )]
     fn test_pitch_class_add_int(
         staff_position: StaffPosition,
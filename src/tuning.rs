@@ -2,7 +2,14 @@ use std::{fmt, str::FromStr};
 
 use clap::ValueEnum;
 
-use crate::{Interval, Note, Semitones};
+use crate::{Interval, Note, Octave, Semitones, PITCH_CLASS_COUNT, STRING_COUNT};
+
+/// Custom error for strings that cannot be parsed into tunings.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("could not parse tuning name '{name}'")]
+pub struct ParseTuningError {
+    name: String,
+}
 
 // Using clap's `value_enum` macro allows the specification of all Tuning
 // variants as `possible_values` for the CLI `--tuning` option.
@@ -15,6 +22,11 @@ pub enum Tuning {
 }
 
 impl Tuning {
+    /// Iterator over the values of the Tuning enum.
+    pub fn values() -> impl Iterator<Item = Self> {
+        [Self::C, Self::D, Self::G].iter().copied()
+    }
+
     pub fn get_semitones(self) -> Semitones {
         match self {
             Self::C => 0,
@@ -31,6 +43,13 @@ impl Tuning {
         }
     }
 
+    /// Return the notes of the open strings, from the G string (or its counterpart)
+    /// to the A string (or its counterpart).
+    ///
+    /// Their canonical spelling is derived by moving G C E A up by the tuning's
+    /// interval, e.g. A D F# B (and not A D Gb B) for D tuning. This is the
+    /// spelling used both in the list of tunings and in the string labels
+    /// of chord charts.
     pub fn roots(self) -> impl Iterator<Item = Note> + 'static {
         let interval = self.get_interval();
 
@@ -38,6 +57,43 @@ impl Tuning {
             .iter()
             .map(move |c| Note::from_str(c).unwrap() + interval)
     }
+
+    /// Return the octaves of the open strings.
+    ///
+    /// The C and D tunings are reentrant, i.e. the G string (or A string
+    /// respectively) is tuned higher than the C string (G4 C4 E4 A4 for C tuning).
+    /// With `low_g`, it is tuned an octave lower instead (G3 C4 E4 A4).
+    /// The baritone G tuning is linear anyway (D3 G3 B3 E4), so `low_g` has
+    /// no effect there.
+    pub fn octaves(self, low_g: bool) -> [Octave; STRING_COUNT] {
+        match (self, low_g) {
+            (Self::C | Self::D, false) => [4, 4, 4, 4],
+            (Self::C | Self::D, true) => [3, 4, 4, 4],
+            (Self::G, _) => [3, 3, 3, 4],
+        }
+    }
+
+    /// Return the octaves of the open strings (see `octaves()`) after tuning
+    /// all strings up by `offset` semitones (or down if negative). The octave
+    /// changes whenever a string crosses a C, e.g. the C string of C tuning
+    /// tuned down a half step plays B3 instead of C4.
+    pub fn octaves_with_offset(self, low_g: bool, offset: i8) -> [Octave; STRING_COUNT] {
+        let mut octaves = self.octaves(low_g);
+
+        for (octave, root) in octaves.iter_mut().zip(self.roots()) {
+            *octave = offset_octave(*octave, root, offset);
+        }
+
+        octaves
+    }
+}
+
+/// Return the octave of the note `root` in `octave` after transposing it
+/// by `offset` semitones.
+pub(crate) fn offset_octave(octave: Octave, root: Note, offset: i8) -> Octave {
+    let semitones =
+        octave as i16 * PITCH_CLASS_COUNT as i16 + root.pitch_class as i16 + offset as i16;
+    semitones.div_euclid(PITCH_CLASS_COUNT as i16) as Octave
 }
 
 impl fmt::Display for Tuning {
@@ -51,3 +107,112 @@ impl fmt::Display for Tuning {
         write!(f, "{s}")
     }
 }
+
+impl FromStr for Tuning {
+    type Err = ParseTuningError;
+
+    /// Parse the name of a tuning as returned by `to_string()`, e.g. `D`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::values()
+            .find(|tuning| tuning.to_string() == s)
+            .ok_or_else(|| ParseTuningError {
+                name: s.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{FretPattern, Voicing};
+
+    #[rstest(
+        tuning,
+        roots,
+        case(Tuning::C, "G C E A"),
+        case(Tuning::D, "A D F# B"),
+        case(Tuning::G, "D G B E")
+    )]
+    fn test_roots(tuning: Tuning, roots: &str) {
+        let s = tuning.roots().map(|r| r.to_string()).collect::<Vec<_>>();
+        assert_eq!(s.join(" "), roots);
+    }
+
+    #[rstest(tuning, case(Tuning::C), case(Tuning::D), case(Tuning::G))]
+    fn test_from_str_round_trip(tuning: Tuning) {
+        assert_eq!(tuning.to_string().parse::<Tuning>().unwrap(), tuning);
+    }
+
+    #[rstest(s, case(""), case("c"), case("A"), case("Bb"), case("CD"))]
+    fn test_from_str_fail(s: &str) {
+        assert!(s.parse::<Tuning>().is_err());
+    }
+
+    #[rstest(tuning, case(Tuning::C), case(Tuning::D), case(Tuning::G))]
+    fn test_display_matches_value_enum(tuning: Tuning) {
+        // The CLI option --tuning accepts exactly the names that are displayed.
+        let value = tuning.to_possible_value().unwrap();
+        assert_eq!(value.get_name(), tuning.to_string());
+    }
+
+    #[rstest(
+        tuning,
+        pattern,
+        case(Tuning::C, "0003"),
+        case(Tuning::D, "0003"),
+        case(Tuning::D, "2220"),
+        case(Tuning::G, "0000")
+    )]
+    fn test_roots_match_voicing_roots(tuning: Tuning, pattern: &str) {
+        // The string labels of chord charts are spelled like the open strings.
+        let pattern = FretPattern::from_str(pattern).unwrap();
+        let voicing = Voicing::new(pattern, tuning);
+        let s1 = tuning.roots().map(|r| r.to_string()).collect::<Vec<_>>();
+        let s2 = voicing.roots().map(|r| r.to_string()).collect::<Vec<_>>();
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn test_values() {
+        let tunings: Vec<_> = Tuning::values().collect();
+        assert_eq!(tunings, Tuning::value_variants());
+    }
+
+    #[rstest(
+        tuning,
+        low_g,
+        octaves,
+        case(Tuning::C, false, [4, 4, 4, 4]),
+        case(Tuning::C, true, [3, 4, 4, 4]),
+        case(Tuning::D, false, [4, 4, 4, 4]),
+        case(Tuning::D, true, [3, 4, 4, 4]),
+        case(Tuning::G, false, [3, 3, 3, 4]),
+        case(Tuning::G, true, [3, 3, 3, 4])
+    )]
+    fn test_octaves(tuning: Tuning, low_g: bool, octaves: [Octave; STRING_COUNT]) {
+        assert_eq!(tuning.octaves(low_g), octaves);
+    }
+
+    #[rstest(
+        tuning,
+        low_g,
+        offset,
+        octaves,
+        case(Tuning::C, false, 0, [4, 4, 4, 4]),
+        case(Tuning::C, false, -1, [4, 3, 4, 4]),
+        case(Tuning::C, true, -1, [3, 3, 4, 4]),
+        case(Tuning::C, false, 3, [4, 4, 4, 5]),
+        case(Tuning::G, false, 1, [3, 3, 4, 4]),
+        case(Tuning::G, false, -2, [3, 3, 3, 4])
+    )]
+    fn test_octaves_with_offset(
+        tuning: Tuning,
+        low_g: bool,
+        offset: i8,
+        octaves: [Octave; STRING_COUNT],
+    ) {
+        assert_eq!(tuning.octaves_with_offset(low_g, offset), octaves);
+    }
+}
@@ -2,16 +2,41 @@ use std::{
     cmp::{max, min, Ordering},
     convert::{TryFrom, TryInto},
     fmt,
+    ops::RangeInclusive,
     slice::Iter,
 };
 
 use itertools::Itertools;
 
 use crate::{
-    Chord, Distance, Fingering, FretID, FretPattern, Note, PitchClass, Tuning, UkeString,
-    FINGER_COUNT, STRING_COUNT,
+    tuning::offset_octave, Chord, Distance, Fingering, FretID, FretPattern, Interval, Note, Octave,
+    PitchClass, Semitones, Tuning, UkeString, FINGER_COUNT, MAX_FRET_ID, PITCH_CLASS_COUNT,
+    POSITION_WIDTH, STRING_COUNT,
 };
 
+/// Reasons why a chord voicing cannot be played.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Unplayable {
+    #[error("voicing spans {span} frets, but at most {max_span} are allowed")]
+    SpanTooWide {
+        span: Semitones,
+        max_span: Semitones,
+    },
+    #[error("voicing needs {needed} fingers, but only {FINGER_COUNT} are available")]
+    TooManyFingers { needed: usize },
+    #[error("voicing needs a barre, but an open string is played below it")]
+    OpenStringBelowBarre,
+}
+
+/// Reasons why a list of frets does not describe a valid voicing.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum VoicingError {
+    #[error("expected {STRING_COUNT} frets (one per string), but got {count}")]
+    WrongFretCount { count: usize },
+    #[error("fret {fret} is out of range (frets 0 to {MAX_FRET_ID} are supported)")]
+    FretOutOfRange { fret: FretID },
+}
+
 /// A chord voicing.
 ///
 /// The voicing of a chord describes the order of the individual notes within
@@ -21,8 +46,51 @@ use crate::{
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Voicing {
     uke_strings: [UkeString; STRING_COUNT],
+    /// Strings that are not played.
+    muted: [bool; STRING_COUNT],
+    /// Octaves of the open strings.
+    octaves: [Octave; STRING_COUNT],
 }
 
+/// The position of the barre of a barre chord.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarreInfo {
+    /// The fret at which the strings are pressed down by the barre.
+    pub fret: FretID,
+    /// The indices of the strings covered by the barre, counting from
+    /// the G string (or its counterpart) as 0 to the A string as 3.
+    pub strings: RangeInclusive<usize>,
+}
+
+/// The way the notes of a four-note voicing are spread across the octaves,
+/// as used to categorize voicings in jazz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoicingType {
+    /// All notes lie within an octave, e.g. C4 E4 G4 B4.
+    Close,
+    /// A close voicing with its second highest note dropped by an octave,
+    /// e.g. G3 C4 E4 B4.
+    Drop2,
+    /// A close voicing with its third highest note dropped by an octave,
+    /// e.g. E3 C4 G4 B4.
+    Drop3,
+}
+
+impl fmt::Display for VoicingType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Close => "close",
+            Self::Drop2 => "drop 2",
+            Self::Drop3 => "drop 3",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+/// Extra difficulty of a voicing that has to be played as a barre chord.
+const BARRE_DIFFICULTY: u8 = 3;
+
 impl Voicing {
     // Create a Voicing instance from a set of frets and a tuning.
     // As there is no information about a certain chord for which
@@ -38,9 +106,74 @@ impl Voicing {
             .map(|(root, &fret)| (root, fret, root + fret))
             .collect();
 
+        let muted: Vec<bool> = fret_pattern.muted().copied().collect();
+
         Self {
             uke_strings: uke_strings.try_into().unwrap(),
+            muted: muted.try_into().unwrap(),
+            octaves: tuning.octaves(false),
+        }
+    }
+
+    /// Create a Voicing instance from a list of frets (one per string),
+    /// e.g. from untrusted input. In contrast to `new`, this does not panic but
+    /// returns an error if the number of frets does not match the number of
+    /// strings or if a fret is beyond `MAX_FRET_ID`.
+    ///
+    /// ```
+    /// use ukebox::{Tuning, Voicing, VoicingError};
+    ///
+    /// assert!(Voicing::try_new(&[0, 0, 0, 3], Tuning::C).is_ok());
+    /// assert_eq!(
+    ///     Voicing::try_new(&[0, 0, 3], Tuning::C),
+    ///     Err(VoicingError::WrongFretCount { count: 3 })
+    /// );
+    /// ```
+    pub fn try_new(frets: &[FretID], tuning: Tuning) -> Result<Self, VoicingError> {
+        let frets: [FretID; STRING_COUNT] = frets
+            .try_into()
+            .map_err(|_| VoicingError::WrongFretCount { count: frets.len() })?;
+
+        if let Some(&fret) = frets.iter().find(|&&f| f > MAX_FRET_ID) {
+            return Err(VoicingError::FretOutOfRange { fret });
         }
+
+        Ok(Self::new(frets, tuning))
+    }
+
+    /// Create a Voicing instance from an array containing one fret per string.
+    ///
+    /// ```
+    /// use ukebox::{FretPattern, Tuning, Voicing};
+    ///
+    /// let voicing = Voicing::from_frets([0, 0, 0, 3], Tuning::C);
+    /// assert_eq!(voicing.fret_array(), [0, 0, 0, 3]);
+    ///
+    /// let fret_pattern: FretPattern = "0003".parse().unwrap();
+    /// assert_eq!(voicing, Voicing::new(fret_pattern, Tuning::C));
+    /// ```
+    pub fn from_frets(frets: [FretID; STRING_COUNT], tuning: Tuning) -> Self {
+        Self::new(frets, tuning)
+    }
+
+    /// Tune all strings up by `semitones` (or down if negative) while keeping
+    /// the frets, e.g. to account for an instrument tuned down a half step.
+    /// The octaves of the open strings are adjusted accordingly.
+    pub fn with_tuning_offset(mut self, semitones: i8) -> Self {
+        for (octave, (root, _f, _n)) in self.octaves.iter_mut().zip(self.uke_strings.iter()) {
+            *octave = offset_octave(*octave, *root, semitones);
+        }
+
+        self.uke_strings = self
+            .uke_strings
+            .map(|(r, f, n)| (r.transpose(semitones), f, n.transpose(semitones)));
+        self
+    }
+
+    /// Set the octaves of the open strings, e.g. to account for a low G string.
+    pub fn with_octaves(mut self, octaves: [Octave; STRING_COUNT]) -> Self {
+        self.octaves = octaves;
+        self
     }
 
     pub fn uke_strings(&self) -> Iter<'_, UkeString> {
@@ -55,8 +188,161 @@ impl Voicing {
         self.uke_strings.iter().map(|(_r, f, _n)| *f)
     }
 
+    /// Return the root notes of the strings as an array.
+    pub fn root_array(&self) -> [Note; STRING_COUNT] {
+        self.uke_strings.map(|(r, _f, _n)| r)
+    }
+
+    /// Return the frets pressed down on the strings as an array.
+    pub fn fret_array(&self) -> [FretID; STRING_COUNT] {
+        self.uke_strings.map(|(_r, f, _n)| f)
+    }
+
+    /// Return the notes of all the strings as an array.
+    /// In contrast to `notes()`, this includes the notes of muted strings.
+    pub fn note_array(&self) -> [Note; STRING_COUNT] {
+        self.uke_strings.map(|(_r, _f, n)| n)
+    }
+
+    /// Return an iterator over the notes played on the strings that are not muted.
     pub fn notes(&self) -> impl Iterator<Item = Note> + '_ {
-        self.uke_strings.iter().map(|(_r, _f, n)| *n)
+        self.uke_strings
+            .iter()
+            .zip(self.muted.iter())
+            .filter(|(_us, &m)| !m)
+            .map(|((_r, _f, n), _m)| *n)
+    }
+
+    /// Return an iterator over the notes played on the (non-muted) strings,
+    /// each together with the octave it sounds in.
+    ///
+    /// In contrast to `notes()`, this allows to determine which note
+    /// actually sounds lowest, e.g. the C string in reentrant tuning.
+    pub fn notes_with_octaves(&self) -> impl Iterator<Item = (Note, Octave)> + '_ {
+        self.string_notes_with_octaves()
+            .zip(self.muted.iter())
+            .filter(|(_n_oct, &m)| !m)
+            .map(|(n_oct, _m)| n_oct)
+    }
+
+    /// Return an iterator over the notes of all the strings (including muted
+    /// ones), each together with the octave it sounds in.
+    fn string_notes_with_octaves(&self) -> impl Iterator<Item = (Note, Octave)> + '_ {
+        self.uke_strings
+            .iter()
+            .zip(self.octaves.iter())
+            .map(|((root, fret, note), octave)| {
+                // Count the semitones from C in the octave 0 to the note played.
+                let semitones = *octave as isize * PITCH_CLASS_COUNT as isize
+                    + root.pitch_class as isize
+                    + *fret as isize;
+                let octave = semitones.div_euclid(PITCH_CLASS_COUNT as isize);
+
+                (*note, octave as Octave)
+            })
+    }
+
+    /// Return the note that sounds lowest in the voicing, i.e. its bass note,
+    /// or `None` if all strings are muted.
+    ///
+    /// In reentrant tuning, this isn't necessarily the note played on the
+    /// first string, e.g. the bass note of 0003 is the C of the open C string.
+    pub fn bass_note(&self) -> Option<Note> {
+        self.notes_with_octaves()
+            .min_by_key(|(n, o)| (*o, n.pitch_class))
+            .map(|(n, _o)| n)
+    }
+
+    /// Return the pitches sounding when playing the voicing as the number of
+    /// semitones from C0, from lowest to highest.
+    fn sounding_pitches(&self) -> Vec<isize> {
+        self.notes_with_octaves()
+            .map(|(n, o)| o as isize * PITCH_CLASS_COUNT as isize + n.pitch_class as isize)
+            .sorted()
+            .collect()
+    }
+
+    /// Return whether the voicing is a close, drop 2 or drop 3 voicing,
+    /// determined from the pitches actually sounding (see `with_octaves()`).
+    /// Return `None` if the voicing does not consist of four different notes
+    /// or if it is none of these types.
+    pub fn voicing_type(&self) -> Option<VoicingType> {
+        let pitches = self.sounding_pitches();
+        let octave = PITCH_CLASS_COUNT as isize;
+
+        if pitches.len() != STRING_COUNT
+            || pitches
+                .iter()
+                .map(|p| p.rem_euclid(octave))
+                .unique()
+                .count()
+                != STRING_COUNT
+        {
+            return None;
+        }
+
+        if pitches[3] - pitches[0] < octave {
+            return Some(VoicingType::Close);
+        }
+
+        // Raise the lowest note by an octave. If this results in a close voicing,
+        // the position the note moves to tells which note had been dropped.
+        let raised = pitches[0] + octave;
+        let mut close = [&pitches[1..], &[raised]].concat();
+        close.sort_unstable();
+
+        if close[3] - close[0] >= octave {
+            return None;
+        }
+
+        match close.iter().position(|&p| p == raised) {
+            Some(2) => Some(VoicingType::Drop2),
+            Some(1) => Some(VoicingType::Drop3),
+            _ => None,
+        }
+    }
+
+    /// Compare two voicings by the pitches they actually sound, lowest first.
+    /// So the voicing with the lower bass note comes first, regardless of
+    /// the strings and frets it is played on. Ties are broken by comparing
+    /// the next higher pitches and finally by the default ordering.
+    ///
+    /// In contrast to the default `Ord`, this depends on the octaves of the
+    /// open strings (see `with_octaves()`).
+    pub fn cmp_by_pitch(&self, other: &Self) -> Ordering {
+        self.sounding_pitches()
+            .cmp(&other.sounding_pitches())
+            .then_with(|| self.cmp(other))
+    }
+
+    /// Return `true` if the voicing is in root position, i.e. if its
+    /// bass note is the root of `chord`.
+    pub fn is_root_position(&self, chord: &Chord) -> bool {
+        self.bass_note() == Some(chord.root)
+    }
+
+    /// Return an iterator over the strings' muted states.
+    pub fn muted(&self) -> Iter<'_, bool> {
+        self.muted.iter()
+    }
+
+    /// Return `true` if at least one (non-muted) string is played open.
+    pub fn contains_open_string(&self) -> bool {
+        self.frets().zip(self.muted()).any(|(f, &m)| f == 0 && !m)
+    }
+
+    /// Return the notes that are played on more than one (non-muted) string,
+    /// e.g. the root C in the C major voicing 0003 (G C E C).
+    pub fn doubled_notes(&self) -> Vec<Note> {
+        self.notes().duplicates().collect()
+    }
+
+    /// Return the notes that are played in the same octave on more than one
+    /// (non-muted) string, i.e. in unison, e.g. the G4 in 0433 (G4 E4 G4 C5).
+    /// In contrast to `doubled_notes()`, notes doubled an octave apart are
+    /// not included.
+    pub fn unisons(&self) -> Vec<(Note, Octave)> {
+        self.notes_with_octaves().duplicates().collect()
     }
 
     /// Return the overall number of strings pressed down when playing
@@ -99,42 +385,110 @@ impl Voicing {
             .eq(chord.played_notes().sorted().dedup())
     }
 
-    pub fn get_chords(&self) -> Vec<Chord> {
-        let mut chords = vec![];
+    /// Return `true` if the voicing contains all the notes required
+    /// to play the given `chord` while not containing any notes foreign
+    /// to the chord. Optional notes (such as the fifth) may be omitted.
+    pub fn spells_out_required(&self, chord: &Chord) -> bool {
+        let notes: Vec<Note> = self.notes().collect();
 
-        let mut pitches: Vec<PitchClass> = self.notes().map(|n| n.pitch_class).collect();
-        pitches.sort();
-        pitches.dedup();
+        chord.required_notes().all(|n| notes.contains(&n))
+            && notes.iter().all(|n| chord.notes.contains(n))
+    }
 
-        // Rotate pitch class list and collect all matching chords.
-        // For example, try [C, DSharp, GSharp], [DSharp, GSharp, C], [GSharp, C, FSharp].
-        for _ in 0..pitches.len() {
-            if let Ok(chord) = Chord::try_from(&pitches[..]) {
-                chords.push(chord);
+    /// Return a copy of the voicing in which the notes played on the strings
+    /// are spelled the same way as in `chord`, e.g. Bb instead of A# for Bb major.
+    /// Notes that are not part of `chord` are left unchanged.
+    pub fn respell(&self, chord: &Chord) -> Self {
+        let mut voicing = *self;
+
+        for (_root, _fret, note) in voicing.uke_strings.iter_mut() {
+            if let Some(n) = chord.notes.iter().find(|n| *n == note) {
+                *note = *n;
             }
+        }
 
+        voicing
+    }
+
+    /// Return the distinct pitch classes of the voicing once for each of them
+    /// being considered as the root of a chord, i.e. rotated so that the
+    /// candidate root comes first.
+    /// For example, return [C, DSharp, GSharp], [DSharp, GSharp, C] and [GSharp, C, DSharp].
+    pub fn root_rotations(&self) -> Vec<Vec<PitchClass>> {
+        // Pitch classes are ordered from C to B.
+        let mut pitches: Vec<PitchClass> = self
+            .notes()
+            .map(|n| n.pitch_class)
+            .sorted()
+            .dedup()
+            .collect();
+
+        let mut rotations = vec![];
+
+        for _ in 0..pitches.len() {
+            rotations.push(pitches.clone());
             pitches.rotate_left(1);
         }
 
-        chords.sort();
-        chords
+        rotations
+    }
+
+    pub fn get_chords(&self) -> Vec<Chord> {
+        // Try all rotations of the pitch class list and collect all matching chords.
+        // List simpler chord types first (see the ordering of `ChordType`).
+        self.root_rotations()
+            .iter()
+            .filter_map(|pitches| Chord::try_from(&pitches[..]).ok())
+            .sorted_by_key(|chord| (chord.chord_type, chord.root))
+            .collect()
+    }
+
+    /// If the voicing's notes are built from stacked perfect fourths (such as
+    /// the quartal voicings used in modern jazz, e.g. A D G C), return them in
+    /// this order, starting with the note that the stack is built upon.
+    ///
+    /// At least four different notes are needed, as a stack of two fourths
+    /// (e.g. G C F) is just a suspended chord. Note that `get_chords()` finds
+    /// a (tertian) 7sus4 chord for the same notes, e.g. D7sus4 for A D G C.
+    pub fn quartal_notes(&self) -> Option<Vec<Note>> {
+        let pitches: Vec<PitchClass> = self.notes().map(|n| n.pitch_class).unique().collect();
+
+        if pitches.len() < 4 {
+            return None;
+        }
+
+        self.notes().find_map(|lowest| {
+            let stack: Vec<Note> = (1..pitches.len())
+                .scan(lowest, |note, _| {
+                    *note = *note + Interval::PerfectFourth;
+                    Some(*note)
+                })
+                .collect();
+
+            stack
+                .iter()
+                .all(|n| pitches.contains(&n.pitch_class))
+                .then(|| [vec![lowest], stack].concat())
+        })
     }
 
     /// Return `true` if the current voicing requires the player to play a barre chord.
     /// For this, I took some inspiration from
     /// https://github.com/hyvyys/chord-fingering/blob/master/src/barre.js
     pub fn has_barre(&self) -> bool {
+        // Open strings may not appear below pressed strings.
+        self.has_barre_shape() && !self.has_open_string_below(true)
+    }
+
+    /// Return `true` if the strings pressed down at the lowest fret form a barre
+    /// (see `has_barre`), disregarding any open strings below them.
+    fn has_barre_shape(&self) -> bool {
         let min_fret = self.get_min_pressed_fret();
         let mut min_fret_count = 0;
         let mut pressed_frets = vec![];
 
         for fret in self.frets() {
-            // Open strings may not appear below pressed strings.
-            if fret == 0 {
-                if min_fret_count > 0 {
-                    return false;
-                }
-            } else {
+            if fret != 0 {
                 if fret == min_fret {
                     min_fret_count += 1;
                 }
@@ -164,6 +518,124 @@ impl Voicing {
         min_fret_count >= 2
     }
 
+    /// Return `true` if a string played open comes after a string pressed down
+    /// at the lowest fret. Muted strings only count if `include_muted` is set.
+    fn has_open_string_below(&self, include_muted: bool) -> bool {
+        let min_fret = self.get_min_pressed_fret();
+
+        self.frets()
+            .zip(self.muted())
+            .skip_while(|(f, _m)| *f != min_fret)
+            .any(|(f, &m)| f == 0 && (include_muted || !m))
+    }
+
+    /// Return the fret and the strings covered by the barre if the voicing
+    /// has to be played as a barre chord (see `has_barre`). The barre spans
+    /// from the first to the last string pressed down at the lowest fret,
+    /// e.g. strings 2 to 3 at fret 1 for 0211.
+    pub fn barre_info(&self) -> Option<BarreInfo> {
+        if !self.has_barre() {
+            return None;
+        }
+
+        let fret = self.get_min_pressed_fret();
+        let (first, last) = self
+            .frets()
+            .positions(|f| f == fret)
+            .minmax()
+            .into_option()?;
+
+        Some(BarreInfo {
+            fret,
+            strings: first..=last,
+        })
+    }
+
+    /// Check whether the voicing can physically be played, i.e. whether
+    /// its span does not exceed `max_span`, no open string is played below
+    /// a barre (see `has_barre`) and the frets to be pressed can be reached
+    /// with the fingers available.
+    pub fn playable(&self, max_span: Semitones) -> Result<(), Unplayable> {
+        let span = self.get_span();
+
+        if span > max_span {
+            return Err(Unplayable::SpanTooWide { span, max_span });
+        }
+
+        if self.has_barre_shape() && self.has_open_string_below(false) {
+            return Err(Unplayable::OpenStringBelowBarre);
+        }
+
+        self.check_fingers(FINGER_COUNT)
+    }
+
+    /// Check whether the pressed frets can be reached with `finger_count`
+    /// fingers. If there are more pressed strings than fingers, the strings
+    /// pressed at the lowest fret have to be covered by a barre which
+    /// requires that no open string is played below it.
+    fn check_fingers(&self, finger_count: usize) -> Result<(), Unplayable> {
+        if self.count_pressed_strings() <= finger_count {
+            return Ok(());
+        }
+
+        let min_fret = self.get_min_pressed_fret();
+        let mut barre_started = false;
+        let mut needed = 1;
+
+        for ((_r, fret, _n), &muted) in self.uke_strings.iter().zip(self.muted.iter()) {
+            match *fret {
+                0 if barre_started && !muted => return Err(Unplayable::OpenStringBelowBarre),
+                0 => (),
+                f if f == min_fret => barre_started = true,
+                _ => needed += 1,
+            }
+        }
+
+        match needed {
+            n if n > finger_count => Err(Unplayable::TooManyFingers { needed }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Return `true` if at least three adjacent strings are pressed down at the
+    /// lowest fret, e.g. 0222 or 2220. Such a run of strings is most naturally
+    /// pressed down with a single finger (a partial barre), even if `has_barre`
+    /// does not consider the voicing a barre chord.
+    pub fn has_partial_barre(&self) -> bool {
+        let min_fret = self.get_min_pressed_fret();
+
+        self.frets()
+            .dedup_with_count()
+            .any(|(count, fret)| fret > 0 && fret == min_fret && count >= 3)
+    }
+
+    /// Return a score for how hard the voicing is to play (lower is easier),
+    /// e.g. to pick the voicing best suited for beginners:
+    ///
+    /// * each finger needed counts 1, so open strings make a voicing easier,
+    /// * each fret spanned counts 1, so compact voicings are easier,
+    /// * a (full) barre counts `BARRE_DIFFICULTY` on top,
+    /// * each fret above the first position (frets 1 to 4) counts 1, so
+    ///   voicings high up the neck are harder.
+    ///
+    /// For example, 0003 (C) scores 2, 2010 (F) scores 4 and 5433 (C) scores 10.
+    pub fn difficulty(&self) -> u8 {
+        let fingers = self
+            .fingers_on_strings()
+            .iter()
+            .filter(|&&f| f > 0)
+            .unique()
+            .count() as u8;
+        let barre = match self.has_barre() {
+            true => BARRE_DIFFICULTY,
+            false => 0,
+        };
+
+        let position = self.get_max_fret().saturating_sub(POSITION_WIDTH);
+
+        fingers + self.get_span() + barre + position
+    }
+
     /// Compute a fingering for the current voicing, i.e. assign the player's
     /// fingers to the positions on the fretboard that have to be pressed down.
     /// The return value is an array of numbers representing the fingers
@@ -173,6 +645,8 @@ impl Voicing {
     /// strategy here is based on my own way to play certain chords. For example,
     /// I tend to avoid barre chords if possible, e.g. I play the G major chord
     /// as 0132 and not as 0121.
+    /// Only three or more adjacent strings pressed down at the lowest fret
+    /// are played with a single finger, e.g. 0111 for the A major chord 0222.
     pub fn fingers_on_strings(&self) -> [u8; STRING_COUNT] {
         // Total number of strings on which we need to place our fingers.
         let pressed_strings = self.count_pressed_strings();
@@ -191,6 +665,9 @@ impl Voicing {
             _ => self.get_min_pressed_fret(),
         };
 
+        // Use one finger for all the strings pressed down at the lowest fret.
+        let barre = self.has_barre() || self.has_partial_barre();
+
         let mut fingering = [0; STRING_COUNT];
 
         // Current finger (can have values 1 to 4).
@@ -205,7 +682,7 @@ impl Voicing {
                 if f == fret_id {
                     fingering[i] = finger as u8;
                     used_strings += 1;
-                    if (!self.has_barre() || finger > 1) && finger < FINGER_COUNT {
+                    if (!barre || finger > 1) && finger < FINGER_COUNT {
                         finger += 1;
                     }
                 }
@@ -237,6 +714,18 @@ impl Voicing {
             .sum()
     }
 
+    /// Return the number of strings on which this and another voicing
+    /// play the same note in the same octave, i.e. the number of common tones
+    /// that are held on the same string when moving from one voicing to the other.
+    pub fn common_tones(&self, other: Self) -> u8 {
+        self.string_notes_with_octaves()
+            .zip(self.muted())
+            .zip(other.string_notes_with_octaves().zip(other.muted()))
+            .filter(|((_n1, &m1), (_n2, &m2))| !m1 && !m2)
+            .filter(|((n1, _m1), (n2, _m2))| n1 == n2)
+            .count() as u8
+    }
+
     pub fn fingering_distance(&self, other: Self) -> u8 {
         let l_fingering = Fingering::from(*self);
         let r_fingering = Fingering::from(other);
@@ -248,7 +737,7 @@ impl Voicing {
         let semitone_distance = self.semitone_distance(other);
         let fingering_distance = self.fingering_distance(other);
 
-        Distance::new(semitone_distance, fingering_distance)
+        Distance::new(semitone_distance.into(), fingering_distance.into())
     }
 }
 
@@ -260,10 +749,8 @@ impl PartialOrd for Voicing {
 
 impl Ord for Voicing {
     fn cmp(&self, other: &Self) -> Ordering {
-        // In order to iterate over frets in reversed order, we could implement
-        // DoubleEndedIterator ... or use this hack.
-        let frets1: Vec<FretID> = self.frets().collect();
-        let frets2: Vec<FretID> = other.frets().collect();
+        let frets1 = self.fret_array();
+        let frets2 = other.fret_array();
 
         match self
             .get_min_pressed_fret()
@@ -286,7 +773,7 @@ impl Default for Voicing {
 impl fmt::Debug for Voicing {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let roots = self.roots().map(|r| r.to_string()).collect::<Vec<String>>();
-        let frets = self.frets().collect::<Vec<FretID>>();
+        let frets = self.fret_array();
         let notes = self.notes().map(|n| n.to_string()).collect::<Vec<String>>();
 
         f.debug_struct("Voicing")
@@ -302,6 +789,8 @@ impl From<&[UkeString]> for Voicing {
         Self {
             // Let's assume that all the Vecs coming in here have the correct size.
             uke_strings: uke_strings.try_into().unwrap(),
+            muted: [false; STRING_COUNT],
+            octaves: Tuning::C.octaves(false),
         }
     }
 }
@@ -324,6 +813,38 @@ mod tests {
         assert!(voicing1 < voicing2);
     }
 
+    #[rstest(
+        pattern,
+        open,
+        case("0003", true),
+        case("2220", true),
+        case("x210", true),
+        case("5433", false),
+        case("x232", false),
+        case("xxxx", false)
+    )]
+    fn test_contains_open_string(pattern: FretPattern, open: bool) {
+        let voicing = Voicing::new(pattern, Tuning::C);
+        assert_eq!(voicing.contains_open_string(), open);
+    }
+
+    #[rstest(
+        pattern, doubled,
+        case("0003", vec!["C"]),
+        case("0433", vec!["G"]),
+        case("2010", vec!["A"]),
+        case("0000", vec![]),
+        case("0212", vec![]),
+        case("3003", vec!["C"]),
+        case("3x03", vec![]),
+        case("0 0 3 3", vec!["G", "C"]),
+    )]
+    fn test_doubled_notes(pattern: FretPattern, doubled: Vec<&str>) {
+        let voicing = Voicing::new(pattern, Tuning::C);
+        let doubled: Vec<Note> = doubled.iter().map(|n| n.parse().unwrap()).collect();
+        assert_eq!(voicing.doubled_notes(), doubled);
+    }
+
     #[rstest(
         frets, count,
         case([0, 0, 0, 0], 0),
@@ -373,6 +894,26 @@ mod tests {
         assert_eq!(voicing.spells_out(&chord), spells_out);
     }
 
+    #[rstest(
+        frets, chord, spells_out, spells_out_required,
+        case([0, 0, 0, 3], "C", true, true), // G C E C
+        case([0, 2, 0, 3], "C", false, false), // G D E C
+        case([0, 0, 0, 1], "C7", true, true), // G C E A#
+        case([2, 0, 0, 1], "C13", true, true), // A C E A# (no 5th)
+        case([0, 0, 0, 1], "C13", false, false), // G C E A# (no 13th)
+        case([5, 4, 6, 3], "C7", false, true), // C E A# C (no 5th)
+    )]
+    fn test_spells_out_required(
+        frets: [FretID; STRING_COUNT],
+        chord: Chord,
+        spells_out: bool,
+        spells_out_required: bool,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        assert_eq!(voicing.spells_out(&chord), spells_out);
+        assert_eq!(voicing.spells_out_required(&chord), spells_out_required);
+    }
+
     #[rstest(
         frets, chord, tuning,
         case([0, 0, 0, 3], "C", Tuning::C),
@@ -385,6 +926,60 @@ mod tests {
         assert_eq!(chords, vec![chord]);
     }
 
+    #[rstest(
+        frets, offset, chord,
+        case([0, 0, 0, 3], 0, "C"),
+        case([0, 0, 0, 3], -1, "B"),
+        case([0, 0, 0, 3], 1, "C#"),
+        case([0, 0, 0, 3], 2, "D"),
+        case([2, 2, 2, 0], -2, "C"),
+    )]
+    fn test_get_chords_tuning_offset(frets: [FretID; STRING_COUNT], offset: i8, chord: Chord) {
+        let voicing = Voicing::new(frets, Tuning::C).with_tuning_offset(offset);
+        assert_eq!(voicing.get_chords(), vec![chord]);
+    }
+
+    #[test]
+    fn test_root_rotations() {
+        use PitchClass::*;
+
+        let voicing = Voicing::new([0, 0, 0, 3], Tuning::C);
+        let rotations = voicing.root_rotations();
+        assert_eq!(rotations, vec![vec![C, E, G], vec![E, G, C], vec![G, C, E]]);
+    }
+
+    #[rstest(
+        fret_pattern, chords,
+        // Three-note chords with a muted outer string.
+        case("000x", vec!["C"]), // G C E
+        case("200x", vec!["Am"]), // A C E
+        case("x210", vec!["Dm"]), // D F A
+        case("x232", vec!["G"]), // D G B
+        // Three-note chords with a muted inner string.
+        case("0x03", vec!["C"]), // G E C
+        case("2x13", vec!["F"]), // A F C
+        // Muted strings do not contribute notes.
+        case("x003", vec![]), // C E C
+        case("xxxx", vec![]),
+    )]
+    fn test_get_chords_muted(fret_pattern: FretPattern, chords: Vec<&str>) {
+        let voicing = Voicing::new(fret_pattern, Tuning::C);
+        let chords: Vec<Chord> = chords.iter().map(|c| c.parse().unwrap()).collect();
+        assert_eq!(voicing.get_chords(), chords);
+    }
+
+    #[rstest(
+        fret_pattern, chords,
+        case("0000", vec!["C6", "Am7"]),
+        case("0013", vec!["Csus4", "Fsus2"]),
+        case("10 10 10 10", vec!["A#6", "Gm7"]),
+    )]
+    fn test_get_chords_order(fret_pattern: FretPattern, chords: Vec<&str>) {
+        let voicing = Voicing::new(fret_pattern, Tuning::C);
+        let chords: Vec<Chord> = chords.iter().map(|c| c.parse().unwrap()).collect();
+        assert_eq!(voicing.get_chords(), chords);
+    }
+
     #[rstest(
         frets,
         case([1, 2, 3, 4]),
@@ -448,6 +1043,181 @@ mod tests {
         assert_eq!(voicing.has_barre(), has_barre);
     }
 
+    #[rstest(
+        frets, low_g, voicing_type,
+        // C4 E4 G4 B4
+        case([0, 0, 0, 2], false, Some(VoicingType::Close)),
+        // C4 F4 G4 A#4
+        case([0, 0, 1, 1], false, Some(VoicingType::Close)),
+        // G3 C4 E4 B4
+        case([0, 0, 0, 2], true, Some(VoicingType::Drop2)),
+        // G3 C4 E4 A4
+        case([0, 0, 0, 0], true, Some(VoicingType::Drop2)),
+        // G3 C4 A4 B4
+        case([0, 0, 7, 0], true, Some(VoicingType::Drop3)),
+        // G3 C4 E4 A5
+        case([0, 0, 0, 12], true, None),
+        // Only three different notes: G4 C4 E4 C5
+        case([0, 0, 0, 3], false, None),
+    )]
+    fn test_voicing_type(
+        frets: [FretID; STRING_COUNT],
+        low_g: bool,
+        voicing_type: Option<VoicingType>,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C).with_octaves(Tuning::C.octaves(low_g));
+        assert_eq!(voicing.voicing_type(), voicing_type);
+    }
+
+    #[rstest(fret_pattern, case("x000"), case("000x"))]
+    fn test_voicing_type_muted(fret_pattern: FretPattern) {
+        let voicing = Voicing::new(fret_pattern, Tuning::C);
+        assert_eq!(voicing.voicing_type(), None);
+    }
+
+    #[rstest(
+        frets,
+        case(vec![0, 0, 0, 3]),
+        case(vec![0, 0, 0, 0]),
+        case(vec![21, 21, 21, 21]),
+    )]
+    fn test_try_new(frets: Vec<FretID>) {
+        let voicing = Voicing::try_new(&frets, Tuning::C).unwrap();
+        assert_eq!(voicing.frets().collect::<Vec<_>>(), frets);
+    }
+
+    #[rstest(
+        frets, error,
+        case(vec![], VoicingError::WrongFretCount { count: 0 }),
+        case(vec![0, 0, 3], VoicingError::WrongFretCount { count: 3 }),
+        case(vec![0, 0, 0, 3, 0], VoicingError::WrongFretCount { count: 5 }),
+        case(vec![0, 0, 0, 22], VoicingError::FretOutOfRange { fret: 22 }),
+        case(vec![255, 0, 0, 0], VoicingError::FretOutOfRange { fret: 255 }),
+    )]
+    fn test_try_new_error(frets: Vec<FretID>, error: VoicingError) {
+        assert_eq!(Voicing::try_new(&frets, Tuning::C), Err(error));
+    }
+
+    #[rstest(
+        frets, barre_info,
+        case([0, 0, 0, 3], None),
+        case([0, 1, 2, 1], None),
+        case([1, 2, 1, 2], None),
+        case([0, 2, 1, 1], Some((1, 2..=3))),
+        case([1, 1, 1, 1], Some((1, 0..=3))),
+        case([3, 1, 1, 1], Some((1, 1..=3))),
+        case([1, 3, 3, 1], Some((1, 0..=3))),
+        case([1, 1, 2, 1], Some((1, 0..=3))),
+        case([1, 1, 1, 3], Some((1, 0..=2))),
+        case([5, 5, 5, 7], Some((5, 0..=2))),
+    )]
+    fn test_barre_info(
+        frets: [FretID; STRING_COUNT],
+        barre_info: Option<(FretID, RangeInclusive<usize>)>,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        let barre_info = barre_info.map(|(fret, strings)| BarreInfo { fret, strings });
+        assert_eq!(voicing.barre_info(), barre_info);
+    }
+
+    #[rstest(
+        frets, max_span, result,
+        case([0, 0, 0, 3], 4, Ok(())),
+        case([2, 4, 1, 3], 4, Ok(())),
+        case([1, 1, 1, 5], 5, Ok(())),
+        case([1, 1, 1, 5], 4, Err(Unplayable::SpanTooWide { span: 5, max_span: 4 })),
+        case([0, 2, 3, 7], 5, Err(Unplayable::SpanTooWide { span: 6, max_span: 5 })),
+        case([1, 1, 0, 2], 4, Err(Unplayable::OpenStringBelowBarre)),
+        case([2, 2, 0, 4], 4, Err(Unplayable::OpenStringBelowBarre)),
+        case([2, 2, 2, 0], 4, Ok(())),
+        case([0, 2, 2, 3], 4, Ok(())),
+    )]
+    fn test_playable(
+        frets: [FretID; STRING_COUNT],
+        max_span: Semitones,
+        result: Result<(), Unplayable>,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        assert_eq!(voicing.playable(max_span), result);
+    }
+
+    #[rstest(
+        frets, finger_count, result,
+        case([2, 1, 1, 1], 2, Ok(())),
+        case([0, 1, 1, 3], 2, Ok(())),
+        case([1, 2, 1, 1], 1, Err(Unplayable::TooManyFingers { needed: 2 })),
+        case([1, 2, 3, 1], 2, Err(Unplayable::TooManyFingers { needed: 3 })),
+        case([1, 0, 1, 2], 2, Err(Unplayable::OpenStringBelowBarre)),
+        case([1, 1, 0, 2], 2, Err(Unplayable::OpenStringBelowBarre)),
+    )]
+    fn test_check_fingers(
+        frets: [FretID; STRING_COUNT],
+        finger_count: usize,
+        result: Result<(), Unplayable>,
+    ) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        assert_eq!(voicing.check_fingers(finger_count), result);
+    }
+
+    #[rstest(
+        frets, has_partial_barre,
+        case([0, 0, 0, 0], false),
+        case([0, 2, 2, 0], false),
+        case([2, 2, 0, 2], false),
+        case([0, 2, 2, 2], true),
+        case([2, 2, 2, 0], true),
+        case([2, 2, 2, 3], true),
+        case([1, 4, 4, 4], false),
+        case([3, 3, 3, 1], false),
+    )]
+    fn test_has_partial_barre(frets: [FretID; STRING_COUNT], has_partial_barre: bool) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        assert_eq!(voicing.has_partial_barre(), has_partial_barre);
+    }
+
+    #[rstest(
+        pattern,
+        quartal_notes,
+        // The "So What" chord (E A D G B) without its top note.
+        case("9 9 10 10", "E A D G"),
+        case("2233", "A D G C"),
+        case("0 10 8 8", "G C F Bb"),
+        // Stacks of two fourths are suspended chords.
+        case("2230", ""),
+        case("x563", ""),
+        case("0013", ""),
+        // Other chords.
+        case("0003", ""),
+        case("0001", ""),
+        case("xxxx", ""),
+    )]
+    fn test_quartal_notes(pattern: FretPattern, quartal_notes: &str) {
+        let voicing = Voicing::new(pattern, Tuning::C);
+        let s = voicing
+            .quartal_notes()
+            .map(|notes| notes.iter().join(" "))
+            .unwrap_or_default();
+        assert_eq!(s, quartal_notes);
+    }
+
+    #[rstest(
+        frets, difficulty,
+        case([0, 0, 0, 0], 0),
+        case([0, 0, 0, 3], 2),
+        case([2, 2, 2, 0], 2),
+        case([2, 0, 1, 0], 4),
+        case([0, 2, 3, 2], 5),
+        // Barre chords.
+        case([3, 2, 1, 1], 9),
+        case([5, 4, 3, 3], 10),
+        // High up the neck.
+        case([9, 9, 9, 0], 7),
+    )]
+    fn test_difficulty(frets: [FretID; STRING_COUNT], difficulty: u8) {
+        let voicing = Voicing::new(frets, Tuning::C);
+        assert_eq!(voicing.difficulty(), difficulty);
+    }
+
     #[rstest(
         frets, fingering,
         // No fingered strings.
@@ -462,14 +1232,18 @@ mod tests {
         case([2, 0, 1, 0], [2, 0, 1, 0]),
         case([2, 0, 0, 3], [2, 0, 0, 3]),
         // Three fingered strings without barre.
-        case([2, 2, 2, 0], [1, 2, 3, 0]),
         case([0, 2, 3, 2], [0, 1, 3, 2]),
+        case([2, 2, 0, 2], [1, 2, 0, 3]),
         case([1, 0, 1, 3], [1, 0, 2, 4]),
         case([1, 1, 0, 4], [1, 2, 0, 4]),
         case([3, 0, 3, 1], [3, 0, 4, 1]),
         case([11, 0, 10, 12], [2, 0, 1, 3]),
         // Three fingered strings with barre.
         case([0, 4, 3, 3], [0, 2, 1, 1]),
+        // Three fingered strings with partial barre.
+        case([2, 2, 2, 0], [1, 1, 1, 0]),
+        case([0, 2, 2, 2], [0, 1, 1, 1]),
+        case([0, 1, 1, 1], [0, 1, 1, 1]),
         // Four fingered strings without barre.
         case([2, 3, 2, 3], [1, 3, 2, 4]),
         case([2, 3, 5, 3], [1, 2, 4, 3]),
@@ -515,4 +1289,220 @@ mod tests {
         let voicing2 = Voicing::new(frets2, Tuning::C);
         assert_eq!(voicing1.semitone_distance(voicing2), dist);
     }
+
+    #[rstest(
+        pattern1, pattern2, count,
+        case("0000", "0000", 4),
+        // C -> Am: C and E are held.
+        case("0003", "2000", 2),
+        // C -> F: only C is held.
+        case("0003", "2010", 1),
+        // C -> G7: only G is held.
+        case("0003", "0212", 1),
+        // The same pitch class an octave apart is not held.
+        case("0003", "12 0 0 3", 3),
+        // Muted strings don't hold any tone.
+        case("x003", "x000", 2),
+        case("0003", "x003", 3),
+    )]
+    fn test_common_tones(pattern1: FretPattern, pattern2: FretPattern, count: u8) {
+        let voicing1 = Voicing::new(pattern1, Tuning::C);
+        let voicing2 = Voicing::new(pattern2, Tuning::C);
+        assert_eq!(voicing1.common_tones(voicing2), count);
+    }
+
+    #[rstest(
+        pattern,
+        tuning,
+        low_g,
+        notes,
+        case("0000", Tuning::C, false, "G4 C4 E4 A4"),
+        case("0000", Tuning::C, true, "G3 C4 E4 A4"),
+        case("0003", Tuning::C, false, "G4 C4 E4 C5"),
+        case("5433", Tuning::C, false, "C5 E4 G4 C5"),
+        case("5433", Tuning::C, true, "C4 E4 G4 C5"),
+        case("0000", Tuning::D, false, "A4 D4 F#4 B4"),
+        case("0000", Tuning::D, true, "A3 D4 F#4 B4"),
+        case("0000", Tuning::G, false, "D3 G3 B3 E4"),
+        case("x003", Tuning::C, false, "C4 E4 C5")
+    )]
+    fn test_notes_with_octaves(pattern: FretPattern, tuning: Tuning, low_g: bool, notes: &str) {
+        let voicing = Voicing::new(pattern, tuning).with_octaves(tuning.octaves(low_g));
+        let s = voicing
+            .notes_with_octaves()
+            .map(|(n, o)| format!("{n}{o}"))
+            .collect::<Vec<_>>();
+        assert_eq!(s.join(" "), notes);
+    }
+
+    #[rstest(
+        low_g,
+        lowest,
+        // In reentrant tuning, the open C string sounds lowest.
+        case(false, "C4"),
+        // With a low G string, it's the G string.
+        case(true, "G3"),
+    )]
+    fn test_notes_with_octaves_lowest(low_g: bool, lowest: &str) {
+        let voicing = Voicing::new([0, 0, 0, 0], Tuning::C).with_octaves(Tuning::C.octaves(low_g));
+        let (note, octave) = voicing
+            .notes_with_octaves()
+            .min_by_key(|(n, o)| (*o, n.pitch_class))
+            .unwrap();
+        assert_eq!(format!("{note}{octave}"), lowest);
+    }
+
+    #[rstest(
+        pattern1,
+        pattern2,
+        low_g,
+        ordering,
+        // The bass note of 0003 is C4, the one of 0433 is E4.
+        case("0003", "0433", false, Ordering::Less),
+        // The bass note of 0232 is D4, so it comes after 0003 (C4),
+        // although it comes first by default.
+        case("0232", "0003", false, Ordering::Greater),
+        // Both have C4 in the bass, but 2010 continues with F4
+        // and 0003 with E4.
+        case("2010", "0003", false, Ordering::Greater),
+        // With a low G string, 0003 starts with G3 and 5433 with C4.
+        case("5433", "0003", true, Ordering::Greater),
+        case("0003", "0003", false, Ordering::Equal),
+    )]
+    fn test_cmp_by_pitch(
+        pattern1: FretPattern,
+        pattern2: FretPattern,
+        low_g: bool,
+        ordering: Ordering,
+    ) {
+        let octaves = Tuning::C.octaves(low_g);
+        let voicing1 = Voicing::new(pattern1, Tuning::C).with_octaves(octaves);
+        let voicing2 = Voicing::new(pattern2, Tuning::C).with_octaves(octaves);
+        assert_eq!(voicing1.cmp_by_pitch(&voicing2), ordering);
+    }
+
+    #[rstest(
+        pattern,
+        low_g,
+        unisons,
+        // The root C is doubled an octave apart.
+        case("0003", false, ""),
+        case("0433", false, "G4"),
+        case("0433", true, ""),
+        case("2220", false, "A4"),
+        case("0000", false, ""),
+        case("x433", false, ""),
+    )]
+    fn test_unisons(pattern: FretPattern, low_g: bool, unisons: &str) {
+        let voicing = Voicing::new(pattern, Tuning::C).with_octaves(Tuning::C.octaves(low_g));
+        let s = voicing
+            .unisons()
+            .iter()
+            .map(|(n, o)| format!("{n}{o}"))
+            .collect::<Vec<_>>();
+        assert_eq!(s.join(" "), unisons);
+    }
+
+    #[rstest(
+        pattern,
+        low_g,
+        bass_note,
+        case("0003", false, "C"),
+        case("0003", true, "G"),
+        case("2010", false, "C"),
+        case("2010", true, "A"),
+        case("5433", false, "E"),
+        case("5433", true, "C"),
+        case("x003", false, "C"),
+        case("xxxx", false, "")
+    )]
+    fn test_bass_note(pattern: FretPattern, low_g: bool, bass_note: &str) {
+        let voicing = Voicing::new(pattern, Tuning::C).with_octaves(Tuning::C.octaves(low_g));
+        let bass_note = bass_note.parse::<Note>().ok();
+        assert_eq!(voicing.bass_note(), bass_note);
+    }
+
+    #[rstest(
+        pattern,
+        tuning,
+        offset,
+        bass_note,
+        case("0000", Tuning::C, 0, "C"),
+        case("0000", Tuning::C, -1, "B"),
+        case("0003", Tuning::C, -1, "B"),
+        case("0000", Tuning::G, 0, "D"),
+        case("0000", Tuning::G, 1, "D#"),
+        case("0000", Tuning::G, -2, "C")
+    )]
+    fn test_bass_note_tuning_offset(
+        pattern: FretPattern,
+        tuning: Tuning,
+        offset: i8,
+        bass_note: Note,
+    ) {
+        let voicing = Voicing::new(pattern, tuning).with_tuning_offset(offset);
+        assert_eq!(voicing.bass_note(), Some(bass_note));
+    }
+
+    #[rstest(
+        pattern,
+        chord,
+        low_g,
+        is_root_position,
+        case("0003", "C", false, true),
+        case("0003", "C", true, false),
+        case("5433", "C", false, false),
+        case("5433", "C", true, true),
+        case("0232", "G", false, false),
+        case("0232", "G", true, true),
+        case("2000", "Am", false, false),
+        case("2000", "Am", true, true)
+    )]
+    fn test_is_root_position(
+        pattern: FretPattern,
+        chord: Chord,
+        low_g: bool,
+        is_root_position: bool,
+    ) {
+        let voicing = Voicing::new(pattern, Tuning::C).with_octaves(Tuning::C.octaves(low_g));
+        assert_eq!(voicing.is_root_position(&chord), is_root_position);
+    }
+
+    #[rstest(
+        pattern,
+        chord,
+        notes,
+        case("3211", "Bb", "Bb D F Bb"),
+        case("3211", "A#", "A# D F A#"),
+        case("5343", "Ab", "C Eb Ab C"),
+        case("x343", "Ab", "Eb Ab C"),
+        // Notes foreign to the chord are left unchanged.
+        case("0111", "Bbm", "G Db F Bb"),
+    )]
+    fn test_respell(pattern: FretPattern, chord: Chord, notes: &str) {
+        let voicing = Voicing::new(pattern, Tuning::C);
+        let s = voicing
+            .respell(&chord)
+            .notes()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(s.join(" "), notes);
+    }
+
+    #[rstest(
+        pattern,
+        roots,
+        frets,
+        notes,
+        case("0003", "G C E A", [0, 0, 0, 3], "G C E C"),
+        case("2x13", "G C E A", [2, 0, 1, 3], "A C F C"),
+    )]
+    fn test_arrays(pattern: FretPattern, roots: &str, frets: [FretID; STRING_COUNT], notes: &str) {
+        let voicing = Voicing::new(pattern, Tuning::C);
+        let to_str = |notes: [Note; STRING_COUNT]| notes.map(|n| n.to_string()).join(" ");
+
+        assert_eq!(to_str(voicing.root_array()), roots);
+        assert_eq!(voicing.fret_array(), frets);
+        assert_eq!(to_str(voicing.note_array()), notes);
+    }
 }
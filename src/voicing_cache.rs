@@ -0,0 +1,163 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::{Chord, StaffPosition, Voicing, VoicingConfig};
+
+/// A cache for the voicings of chords so that looking up the same chord with
+/// the same configuration again does not compute its voicings from scratch.
+///
+/// ```
+/// use std::{rc::Rc, str::FromStr};
+///
+/// use ukebox::{Chord, VoicingCache, VoicingConfig};
+///
+/// let chord = Chord::from_str("C").unwrap();
+/// let config = VoicingConfig::default();
+///
+/// let mut cache = VoicingCache::new();
+/// let voicings = cache.voicings(&chord, config);
+/// assert_eq!(voicings.len(), chord.voicings(config).count());
+///
+/// // The second lookup returns the voicings computed before.
+/// assert!(Rc::ptr_eq(&voicings, &cache.voicings(&chord, config)));
+/// ```
+#[derive(Default)]
+pub struct VoicingCache {
+    /// The voicings of each chord and configuration. Chords are compared by
+    /// their pitches, so the staff position of the root is part of the key
+    /// to keep the spelling of the notes, e.g. for C# and Db.
+    voicings: HashMap<(Chord, StaffPosition, VoicingConfig), Rc<Vec<Voicing>>>,
+}
+
+impl VoicingCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the voicings of `chord` for `config` in the same order as
+    /// `Chord::voicings`. They are only computed on the first lookup.
+    pub fn voicings(&mut self, chord: &Chord, config: VoicingConfig) -> Rc<Vec<Voicing>> {
+        let key = (chord.clone(), chord.root.staff_position(), config);
+
+        self.voicings
+            .entry(key)
+            .or_insert_with(|| Rc::new(chord.voicings(config).collect()))
+            .clone()
+    }
+
+    /// Return the number of chord and configuration pairs in the cache.
+    pub fn len(&self) -> usize {
+        self.voicings.len()
+    }
+
+    /// Return `true` if no voicings have been cached yet.
+    pub fn is_empty(&self) -> bool {
+        self.voicings.is_empty()
+    }
+
+    /// Remove all cached voicings.
+    pub fn clear(&mut self) {
+        self.voicings.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{hint::black_box, str::FromStr, time::Instant};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Tuning;
+
+    #[rstest(chord, case("C"), case("C#m7"), case("Dbm7"), case("G13"))]
+    fn test_voicings(chord: Chord) {
+        let config = VoicingConfig::default();
+        let mut cache = VoicingCache::new();
+        assert!(cache.is_empty());
+
+        let voicings = cache.voicings(&chord, config);
+        let expected: Vec<Voicing> = chord.voicings(config).collect();
+        assert_eq!(*voicings, expected);
+        assert_eq!(cache.len(), 1);
+
+        // Looking up the same chord again does not add a new entry.
+        let again = cache.voicings(&chord, config);
+        assert!(Rc::ptr_eq(&voicings, &again));
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_voicings_different_configs() {
+        let chord = Chord::from_str("C").unwrap();
+        let config1 = VoicingConfig::default();
+        let config2 = VoicingConfig {
+            tuning: Tuning::D,
+            ..config1
+        };
+        let mut cache = VoicingCache::new();
+
+        let voicings1 = cache.voicings(&chord, config1);
+        let voicings2 = cache.voicings(&chord, config2);
+        assert!(!Rc::ptr_eq(&voicings1, &voicings2));
+        assert_eq!(*voicings2, chord.voicings(config2).collect::<Vec<_>>());
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_voicings_spelling() {
+        let config = VoicingConfig::default();
+        let sharp = Chord::from_str("C#").unwrap();
+        let flat = Chord::from_str("Db").unwrap();
+        let mut cache = VoicingCache::new();
+
+        // Both chords have the same pitches but are spelled differently.
+        let notes = |voicings: Rc<Vec<Voicing>>| -> Vec<String> {
+            voicings[0].notes().map(|n| n.to_string()).collect()
+        };
+        assert_eq!(
+            notes(cache.voicings(&sharp, config)),
+            ["G#", "C#", "F", "C#"]
+        );
+        assert_eq!(
+            notes(cache.voicings(&flat, config)),
+            ["Ab", "Db", "F", "Db"]
+        );
+        assert_eq!(cache.len(), 2);
+    }
+
+    /// Compare the time needed to look up the voicings of the chords of a song
+    /// in which the same few chords come up again and again, with and without
+    /// the cache. Run with `cargo test --release -- --ignored --nocapture bench_`.
+    #[test]
+    #[ignore]
+    fn bench_voicings_repeated() {
+        let chords: Vec<Chord> = "C G Am F G7 C Dm7 G13"
+            .split(' ')
+            .map(|c| Chord::from_str(c).unwrap())
+            .collect();
+        let config = VoicingConfig::default();
+        let repetitions = 100;
+
+        let start = Instant::now();
+        for _ in 0..repetitions {
+            for chord in chords.iter() {
+                black_box(chord.voicings(config).collect::<Vec<_>>());
+            }
+        }
+        let uncached = start.elapsed();
+
+        let start = Instant::now();
+        let mut cache = VoicingCache::new();
+        for _ in 0..repetitions {
+            for chord in chords.iter() {
+                black_box(cache.voicings(chord, config));
+            }
+        }
+        let cached = start.elapsed();
+
+        println!("{repetitions} repetitions: {uncached:?} without cache, {cached:?} with cache");
+    }
+}
@@ -5,7 +5,8 @@ use petgraph::{algo::all_simple_paths, prelude::NodeIndex, Graph};
 
 use crate::{Chord, ChordSequence, Distance, Semitones, Voicing, VoicingConfig};
 
-const MAX_DIST: Semitones = 10;
+/// Default maximal semitone distance between the voicings of consecutive chords.
+pub const MAX_DIST: Semitones = 10;
 
 /// A graph of voicings to find the optimal voice leading.
 ///
@@ -13,14 +14,45 @@ const MAX_DIST: Semitones = 10;
 /// are weighted by the distances between the voicings. It is used
 /// to find the (by some definition) optimal voice leading for
 /// a given sequence of chords.
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use ukebox::{ChordSequence, VoicingConfig, VoicingGraph};
+///
+/// let chord_seq = ChordSequence::from_str("C F G").unwrap();
+///
+/// let mut voicing_graph = VoicingGraph::new(VoicingConfig::default());
+/// voicing_graph.add(&chord_seq);
+///
+/// let paths: Vec<_> = voicing_graph.paths(3).collect();
+/// assert_eq!(paths.len(), 3);
+///
+/// for (voicings, _dist) in paths.iter() {
+///     // Each path contains one voicing per chord.
+///     assert_eq!(voicings.len(), 3);
+/// }
+///
+/// // The best path comes first.
+/// assert!(paths[0].1 <= paths[1].1);
+/// assert!(paths[1].1 <= paths[2].1);
+/// ```
 pub struct VoicingGraph {
     graph: Graph<Voicing, Distance>,
     start_node: NodeIndex,
     end_node: NodeIndex,
     config: VoicingConfig,
+    /// Reward common tones that are held on the same string
+    common_tones: bool,
+    /// Maximal number of voicings to consider per chord
+    beam_width: Option<usize>,
+    /// Maximal semitone distance between the voicings of consecutive chords
+    max_dist: Semitones,
 }
 
 impl VoicingGraph {
+    /// Create an empty graph whose voicings will be generated
+    /// according to `config`.
     pub fn new(config: VoicingConfig) -> Self {
         let mut graph = Graph::new();
 
@@ -33,20 +65,49 @@ impl VoicingGraph {
             start_node,
             end_node,
             config,
+            common_tones: false,
+            beam_width: None,
+            max_dist: MAX_DIST,
         }
     }
 
+    /// Set whether to prefer voicings that keep the notes shared between
+    /// consecutive chords on the same string. Each common tone held
+    /// reduces the semitone distance between two voicings by one.
+    pub fn with_common_tones(mut self, common_tones: bool) -> Self {
+        self.common_tones = common_tones;
+        self
+    }
+
+    /// Only consider the `beam_width` easiest voicings of each chord, i.e. the ones
+    /// with the lowest difficulty (see `Voicing::difficulty`). This keeps the number of paths through the graph
+    /// manageable for long chord sequences or chords with many voicings.
+    pub fn with_beam_width(mut self, beam_width: Option<usize>) -> Self {
+        self.beam_width = beam_width;
+        self
+    }
+
+    /// Set the maximal semitone distance between the voicings of consecutive
+    /// chords. Transitions between voicings that are further apart are ignored.
+    pub fn with_max_dist(mut self, max_dist: Semitones) -> Self {
+        self.max_dist = max_dist;
+        self
+    }
+
     fn add_nodes(&mut self, chord: &Chord) -> Vec<NodeIndex> {
-        let voicings: Vec<Voicing> = chord.voicings(self.config).collect();
+        let voicings = chord.voicings(self.config);
+
+        let voicings: Vec<Voicing> = match self.beam_width {
+            Some(beam_width) => voicings
+                .sorted_by_key(|voicing| (voicing.difficulty(), *voicing))
+                .take(beam_width)
+                .collect(),
+            None => voicings.collect(),
+        };
 
-        // When determining the "path" of a single chord we want to get the voicing
-        // in the lowest position. To achieve this we have to iterate over the chord's
-        // voicings in reversed order to account for the behaviour of the path-finding
-        // algorithm when all distances are equal.
         voicings
-            .iter()
-            .rev()
-            .map(|voicing| self.graph.add_node(*voicing))
+            .into_iter()
+            .map(|voicing| self.graph.add_node(voicing))
             .collect()
     }
 
@@ -57,16 +118,27 @@ impl VoicingGraph {
 
             let dist = match l {
                 l if *l == self.start_node => Distance::default(),
+                _ if self.common_tones => {
+                    let dist = l_voicing.distance(r_voicing);
+                    let common_tones = l_voicing.common_tones(r_voicing);
+
+                    Distance::new(
+                        dist.semitone_distance().saturating_sub(common_tones.into()),
+                        dist.fingering_distance(),
+                    )
+                }
                 _ => l_voicing.distance(r_voicing),
             };
 
             // Ignore voicings that are too far away from each other.
-            if dist.semitone_distance() <= MAX_DIST {
+            if dist.semitone_distance() <= self.max_dist.into() {
                 self.graph.add_edge(*l, *r, dist);
             }
         }
     }
 
+    /// Add the voicings of all the chords in `chord_seq` to the graph,
+    /// connecting the voicings of each chord to those of the next one.
     pub fn add(&mut self, chord_seq: &ChordSequence) {
         // Add edges from the start node to all the voicings of the first chord.
         let mut prev_nodes = vec![self.start_node];
@@ -84,17 +156,25 @@ impl VoicingGraph {
                 .add_edge(*node, self.end_node, Distance::default());
         }
 
-        // Remove unused nodes.
+        // Remove unused nodes. The start and end node are always kept, so that
+        // their indices stay valid even if no path through the graph is left.
+        let start_node = self.start_node;
         let end_node = self.end_node;
 
         self.graph
-            .retain_nodes(|g, n| g.neighbors(n).count() > 0 || n == end_node);
+            .retain_nodes(|g, n| g.neighbors(n).count() > 0 || n == start_node || n == end_node);
     }
 
     /// Return an iterator over the paths between the voicing nodes.
     /// The path with the lowest distance is presented first. If several paths
     /// have the same overall distance, they are further ranked by fingering
-    /// distance.
+    /// distance. Remaining ties are broken by comparing the voicings of the paths
+    /// one by one, so that the path with the voicings in the lowest positions
+    /// comes first. This way, the order of the paths is deterministic.
+    ///
+    /// Each path consists of one voicing per chord in the sequence and
+    /// the sum of the distances between consecutive voicings. At most
+    /// `max_suggestions` paths are returned.
     pub fn paths(
         &self,
         max_suggestions: usize,
@@ -119,20 +199,271 @@ impl VoicingGraph {
                 .sum()
         };
 
-        let mut paths_with_dist = vec![];
+        let mut paths_with_dist: Vec<_> = all_paths
+            .map(|path| {
+                let voicing_path: Vec<_> = path
+                    .iter()
+                    .enumerate()
+                    // Ignore start and end node.
+                    .filter(|(i, _node)| *i > 0 && *i < path.len() - 1)
+                    .map(|(_i, node)| self.graph[*node])
+                    .collect();
 
-        for path in all_paths.sorted_by_key(weight_sum).take(max_suggestions) {
-            let voicing_path: Vec<_> = path
-                .iter()
-                .enumerate()
-                // Ignore start and end node.
-                .filter(|(i, _node)| *i > 0 && *i < path.len() - 1)
-                .map(|(_i, node)| self.graph[*node])
-                .collect();
+                (voicing_path, weight_sum(&path))
+            })
+            .collect();
 
-            paths_with_dist.push((voicing_path, weight_sum(&path)))
-        }
+        // Break ties between paths of the same distance by comparing their voicings,
+        // so that paths in lower positions are presented first. This makes the result
+        // independent of the order in which the paths have been found.
+        paths_with_dist.sort_by(|(p1, d1), (p2, d2)| d1.cmp(d2).then_with(|| p1.cmp(p2)));
+        paths_with_dist.truncate(max_suggestions);
 
         paths_with_dist.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{hint::black_box, str::FromStr, time::Instant};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{FretID, Tuning};
+
+    #[rstest(
+        chord_seq,
+        common_tones,
+        frets,
+        dist,
+        case(
+            "Dm7 G7 Cmaj7",
+            false,
+            vec![[2, 2, 1, 3], [0, 2, 1, 2], [0, 0, 0, 2]],
+            Distance::new(6, 8)
+        ),
+        // D and F are held from Dm7 to G7, G and B from G7 to Cmaj7.
+        case(
+            "Dm7 G7 Cmaj7",
+            true,
+            vec![[2, 2, 1, 3], [0, 2, 1, 2], [0, 0, 0, 2]],
+            Distance::new(2, 8)
+        ),
+        case(
+            "Am7 D7 G",
+            false,
+            vec![[2, 4, 3, 3], [2, 2, 2, 3], [0, 2, 3, 2]],
+            Distance::new(7, 6)
+        ),
+        case(
+            "Am7 D7 G",
+            true,
+            vec![[2, 4, 3, 3], [2, 2, 2, 3], [0, 2, 3, 2]],
+            Distance::new(4, 6)
+        ),
+    )]
+    fn test_paths(
+        chord_seq: ChordSequence,
+        common_tones: bool,
+        frets: Vec<[FretID; 4]>,
+        dist: Distance,
+    ) {
+        let mut voicing_graph =
+            VoicingGraph::new(VoicingConfig::default()).with_common_tones(common_tones);
+        voicing_graph.add(&chord_seq);
+
+        let (path, path_dist) = voicing_graph.paths(1).next().unwrap();
+        let voicings: Vec<Voicing> = frets
+            .iter()
+            .map(|f| Voicing::new(*f, VoicingConfig::default().tuning))
+            .collect();
+
+        assert_eq!(path, voicings);
+        assert_eq!(path_dist, dist);
+    }
+
+    #[rstest(
+        chord_seq,
+        paths,
+        // All the voicings of a single chord have the same distance.
+        case(
+            "C",
+            vec![
+                vec![[0, 0, 0, 3]],
+                vec![[0, 4, 0, 3]],
+                vec![[0, 4, 3, 3]],
+                vec![[5, 4, 3, 3]],
+            ]
+        ),
+        // Repeating a chord is best done by staying on the same voicing.
+        case(
+            "Am Am",
+            vec![
+                vec![[2, 0, 0, 0], [2, 0, 0, 0]],
+                vec![[2, 0, 0, 3], [2, 0, 0, 3]],
+                vec![[2, 4, 0, 3], [2, 4, 0, 3]],
+                vec![[2, 4, 5, 3], [2, 4, 5, 3]],
+            ]
+        ),
+    )]
+    fn test_paths_ties(chord_seq: ChordSequence, paths: Vec<Vec<[FretID; 4]>>) {
+        let mut voicing_graph = VoicingGraph::new(VoicingConfig::default());
+        voicing_graph.add(&chord_seq);
+
+        let voicing_paths: Vec<Vec<Voicing>> = paths
+            .iter()
+            .map(|path| path.iter().map(|f| Voicing::new(*f, Tuning::C)).collect())
+            .collect();
+
+        let found: Vec<Vec<Voicing>> = voicing_graph.paths(4).map(|(p, _d)| p).collect();
+
+        assert_eq!(found, voicing_paths);
+    }
+
+    #[test]
+    fn test_paths_collected_chord_sequence() {
+        // A I-vi-IV-V progression in C built from chords instead of a string.
+        let chord_seq: ChordSequence = [0, 9, 5, 7]
+            .iter()
+            .zip(["C", "Cm", "C", "C"])
+            .map(|(&semitones, chord)| Chord::from_str(chord).unwrap() + semitones)
+            .collect();
+
+        let mut voicing_graph1 = VoicingGraph::new(VoicingConfig::default());
+        voicing_graph1.add(&chord_seq);
+
+        let mut voicing_graph2 = VoicingGraph::new(VoicingConfig::default());
+        voicing_graph2.add(&ChordSequence::from_str("C Am F G").unwrap());
+
+        assert_eq!(
+            voicing_graph1.paths(1).next().unwrap(),
+            voicing_graph2.paths(1).next().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_paths_beam_width() {
+        let chord_seq = ChordSequence::from_str("C Am F G").unwrap();
+
+        let mut voicing_graph = VoicingGraph::new(VoicingConfig::default());
+        voicing_graph.add(&chord_seq);
+        let path_count = voicing_graph.paths(usize::MAX).count();
+
+        let mut pruned_graph = VoicingGraph::new(VoicingConfig::default()).with_beam_width(Some(3));
+        pruned_graph.add(&chord_seq);
+        let pruned_path_count = pruned_graph.paths(usize::MAX).count();
+
+        // With at most 3 voicings per chord, there are at most 3^4 paths.
+        assert!(pruned_path_count <= 81);
+        assert!(pruned_path_count < path_count);
+
+        // The easiest voicings are kept, so the best path does not change here.
+        assert_eq!(
+            pruned_graph.paths(1).next().unwrap(),
+            voicing_graph.paths(1).next().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_paths_beam_width_long_sequence() {
+        let chord_seq = ChordSequence::from_str("C Am Dm7 G7 Em A7 Dm G13").unwrap();
+
+        let mut voicing_graph =
+            VoicingGraph::new(VoicingConfig::default()).with_beam_width(Some(3));
+        voicing_graph.add(&chord_seq);
+
+        // The start and end node plus at most 3 voicings per chord.
+        assert!(voicing_graph.graph.node_count() <= 2 + 8 * 3);
+
+        let (path, _dist) = voicing_graph.paths(1).next().unwrap();
+        assert_eq!(path.len(), 8);
+    }
+
+    #[rstest(
+        tuning,
+        tuning_offset,
+        case(Tuning::C, 0),
+        case(Tuning::G, 0),
+        case(Tuning::D, -1)
+    )]
+    fn test_paths_beam_width_best_voicings(tuning: Tuning, tuning_offset: i8) {
+        let chord_seq = ChordSequence::from_str("C Am F G7").unwrap();
+        let config = VoicingConfig {
+            tuning,
+            tuning_offset,
+            ..Default::default()
+        };
+
+        let mut voicing_graph = VoicingGraph::new(config)
+            .with_beam_width(Some(1))
+            .with_max_dist(u8::MAX);
+        voicing_graph.add(&chord_seq);
+
+        // Only the voicing that is easiest to play is kept for each chord.
+        let (path, _dist) = voicing_graph.paths(1).next().unwrap();
+        let best: Vec<Voicing> = chord_seq
+            .chords()
+            .map(|chord| chord.best_voicing(config).unwrap())
+            .collect();
+        assert_eq!(path, best);
+    }
+
+    /// Compare the time needed to find the best path through a long chord
+    /// sequence with and without a beam width. Run with
+    /// `cargo test --release -- --ignored --nocapture bench_`.
+    #[test]
+    #[ignore]
+    fn bench_paths_beam_width() {
+        let chord_seq = ChordSequence::from_str("C Am Dm7 G7 Em A7 Dm G13").unwrap();
+
+        for beam_width in [None, Some(3)] {
+            let start = Instant::now();
+            let mut voicing_graph =
+                VoicingGraph::new(VoicingConfig::default()).with_beam_width(beam_width);
+            voicing_graph.add(&chord_seq);
+            black_box(voicing_graph.paths(1).next());
+            println!("beam width {beam_width:?}: {:?}", start.elapsed());
+        }
+    }
+
+    #[rstest(
+        chord_seq,
+        max_dist,
+        found,
+        case("Cmaj9 Abmaj13", MAX_DIST, false),
+        case("Cmaj9 Abmaj13", 11, true),
+        case("C G", 0, false),
+        case("C G", 3, true)
+    )]
+    fn test_paths_max_dist(chord_seq: ChordSequence, max_dist: Semitones, found: bool) {
+        let mut voicing_graph = VoicingGraph::new(VoicingConfig::default()).with_max_dist(max_dist);
+        voicing_graph.add(&chord_seq);
+
+        assert_eq!(voicing_graph.paths(1).next().is_some(), found);
+    }
+
+    #[test]
+    fn test_paths_long_sequence() {
+        // 40 transitions between C (0003) and B (4322) of 10 semitones each.
+        let chord_seq = ChordSequence::from_str(&"C B ".repeat(20)).unwrap();
+
+        let mut voicing_graph = VoicingGraph::new(VoicingConfig::default())
+            .with_beam_width(Some(1))
+            .with_max_dist(48);
+        voicing_graph.add(&chord_seq);
+
+        let (path, dist) = voicing_graph.paths(1).next().unwrap();
+        let expected: Distance = path
+            .iter()
+            .tuple_windows()
+            .map(|(v1, v2)| v1.distance(*v2))
+            .collect::<Vec<_>>()
+            .iter()
+            .sum();
+
+        assert_eq!(path.len(), 40);
+        assert_eq!(dist, expected);
+        assert!(dist.semitone_distance() > u8::MAX.into());
+    }
+}
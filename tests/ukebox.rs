@@ -5,6 +5,7 @@ use assert_cmd::prelude::*; // Add methods on commands
 use indoc::indoc;
 use predicates::prelude::*; // Used for writing assertions
 use rstest::rstest;
+use serde_json::{json, Value};
 
 #[test]
 fn test_no_args() -> Result<(), Box<dyn Error>> {
@@ -22,7 +23,195 @@ fn test_unknown_chord() -> Result<(), Box<dyn Error>> {
     cmd.arg("chart");
     cmd.arg("blafoo");
     cmd.assert().failure().stderr(predicate::str::contains(
-        "error: invalid value 'blafoo' for '<CHORD>': could not parse chord name 'blafoo'",
+        "error: invalid value 'blafoo' for '[CHORD]...': could not parse chord name 'blafoo': no valid root note found",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_misspelled_chord() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    cmd.arg("Cmin7b");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "error: invalid value 'Cmin7b' for '[CHORD]...': could not parse chord name 'Cmin7b': unknown chord type 'min7b' at position 1; did you mean 'min7'?",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_omitted_third() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    cmd.arg("C(no3)");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [C(no3) - C major (no 3rd)]
+
+        A  ||---|---|-4-|---|- C
+        E  ||---|---|-3-|---|- G
+        C o||---|---|---|---|- C
+        G o||---|---|---|---|- G
+
+        "
+    ));
+
+    Ok(())
+}
+
+#[rstest(
+    args,
+    error,
+    case(
+        vec!["chart", "--frets", "12", "--min-fret", "15", "C"],
+        "error: --min-fret 15 exceeds the number of frets (12)"
+    ),
+    case(
+        vec!["chart", "--frets", "10", "--max-fret", "11", "C"],
+        "error: --max-fret 11 exceeds the number of frets (10)"
+    ),
+    // An explicitly given maximal fret is rejected even if it is the default one.
+    case(
+        vec!["chart", "--frets", "10", "--max-fret", "12", "C"],
+        "error: --max-fret 12 exceeds the number of frets (10)"
+    ),
+    case(
+        vec!["voice-lead", "--frets", "12", "--position", "13", "C F"],
+        "error: --position 13 exceeds the number of frets (12)"
+    ),
+    case(
+        vec!["fretboard", "--frets", "12", "--max-fret", "15"],
+        "error: --max-fret 15 exceeds the number of frets (12)"
+    ),
+    case(
+        vec!["chart", "--frets", "22", "C"],
+        "error: invalid value '22' for '--frets <FRET_COUNT>': 22 is not in 1..=21"
+    )
+)]
+fn test_frets_exceeded(args: Vec<&str>, error: &str) -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.args(args);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(error));
+
+    Ok(())
+}
+
+#[rstest(
+    args,
+    stdout,
+    // The default maximal fret is lowered to the last fret.
+    case(vec!["fretboard", "--frets", "3"], indoc!("
+        0   1   2   3
+        A   A#  B   C
+        E   F   F#  G
+        C   C#  D   D#
+        G   G#  A   A#
+    ")),
+    // Voicings beyond the last fret are not found.
+    case(vec!["chart", "--frets", "7", "--min-fret", "6", "C"], "No matching chord voicing was found\n"),
+)]
+fn test_frets(args: Vec<&str>, stdout: &str) -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.args(args);
+    cmd.assert().success().stdout(stdout.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_random() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("random");
+    cmd.arg("--type").arg("maj7,m7,7");
+    cmd.arg("--seed").arg("42");
+    cmd.arg("--count").arg("2");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [C#m7 - C# minor 7th]
+
+        A  ||---|---|---|-1-|- C#
+        E  ||---|---|---|-1-|- G#
+        C  ||---|---|---|-1-|- E
+        G  ||---|---|---|-1-|- B
+
+        [F#maj7 - F# major 7th]
+
+        A  -|---|---|-3-|---|- C#
+        E  -|-1-|---|---|---|- F#
+        C  -|---|---|---|-4-|- F
+        G  -|---|-2-|---|---|- A#
+              2
+
+        "
+    ));
+
+    Ok(())
+}
+
+#[rstest(seed, case("0"), case("1"), case("12345"))]
+fn test_random_seed(seed: &str) -> Result<(), Box<dyn Error>> {
+    let run = || -> Result<String, Box<dyn Error>> {
+        let mut cmd = Command::cargo_bin("ukebox")?;
+        cmd.args(["random", "--type", "m,dim", "--count", "10", "--seed", seed]);
+        let output = cmd.output()?;
+        assert!(output.status.success());
+        Ok(String::from_utf8(output.stdout)?)
+    };
+
+    let output = run()?;
+    // The same seed results in the same chords.
+    assert_eq!(output, run()?);
+
+    // Only chords of the given types are picked.
+    let headers: Vec<&str> = output.lines().filter(|l| l.starts_with('[')).collect();
+    assert_eq!(headers.len(), 10);
+    assert!(headers
+        .iter()
+        .all(|h| h.ends_with(" minor]") || h.ends_with(" diminished]")));
+
+    Ok(())
+}
+
+#[test]
+fn test_random_invalid_type() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("random");
+    cmd.arg("--type").arg("maj7,foo");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "error: invalid value 'foo' for '--type <TYPES>': unknown chord type symbol",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_columns() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    cmd.arg("--all");
+    cmd.arg("--columns").arg("2");
+    cmd.arg("--max-fret").arg("5");
+    cmd.arg("C");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [C - C major]
+
+        A  ||---|---|-3-|---|- C    A  ||---|---|-1-|---|- C
+        E o||---|---|---|---|- E    E o||---|---|---|---|- E
+        C o||---|---|---|---|- C    C  ||---|---|---|-2-|- E
+        G o||---|---|---|---|- G    G o||---|---|---|---|- G
+
+        A  ||---|---|-1-|---|- C    A  -|-1-|---|---|---|- C
+        E  ||---|---|-1-|---|- G    E  -|-1-|---|---|---|- G
+        C  ||---|---|---|-2-|- E    C  -|---|-2-|---|---|- E
+        G o||---|---|---|---|- G    G  -|---|---|-3-|---|- C
+                                          3
+
+        "
     ));
 
     Ok(())
@@ -47,12 +236,38 @@ fn test_invalid_pattern() -> Result<(), Box<dyn Error>> {
     cmd.arg("name");
     cmd.arg("blafoo");
     cmd.assert().failure().stderr(predicate::str::contains(
-        "error: invalid value 'blafoo' for '<FRET_PATTERN>': fret pattern has wrong format (should be something like 1234 or '7 8 9 10')",
+        "error: invalid value 'blafoo' for '[FRET_PATTERN]': fret pattern has wrong format (should be something like 1234 or '7 8 9 10')",
     ));
 
     Ok(())
 }
 
+#[rstest(
+    fret_pattern,
+    error,
+    case("2,2,2,y", "invalid fret 'y'"),
+    case("78910", "frets above 9 need to be separated"),
+    case("2-2-2", "fret pattern needs exactly 4 frets, found 3")
+)]
+fn test_invalid_pattern_details(fret_pattern: &str, error: &str) -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg(fret_pattern);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(error));
+
+    Ok(())
+}
+
+#[rstest(fret_pattern, case("7-7-7-10"), case("7,7,7,10"), case("7 7 7 10\n"))]
+fn test_name_separators(fret_pattern: &str) -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg(fret_pattern);
+    cmd.assert().success().stdout("G - G major\n");
+
+    Ok(())
+}
+
 #[test]
 fn test_unknown_pattern() -> Result<(), Box<dyn Error>> {
     let mut cmd = Command::cargo_bin("ukebox")?;
@@ -78,6 +293,50 @@ fn test_no_voicing_seq_found() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[rstest(
+    ascii_only,
+    symbols,
+    case(false, "C half-diminished 7th - Cm7b5, Cø, Cø7"),
+    case(true, "C half-diminished 7th - Cm7b5\n")
+)]
+fn test_chords_ascii_only(
+    ascii_only: bool,
+    symbols: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chords");
+    if ascii_only {
+        cmd.arg("--ascii-only");
+    }
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(symbols));
+
+    if ascii_only {
+        let output = cmd.output()?;
+        assert!(output.stdout.is_ascii());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_tunings() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("tunings");
+    cmd.assert().success().stdout(indoc!(
+        "
+        Supported tunings
+
+        C: G C E A (standard)
+        D: A D F# B
+        G: D G B E
+        "
+    ));
+
+    Ok(())
+}
+
 #[rstest(
     chord,
     chart,
@@ -125,14 +384,15 @@ fn test_chart(chord: &str, chart: &'static str) -> Result<(), Box<dyn Error + 's
 
 #[rstest(
     chord,
-    tuning,
+    min_fret,
     chart,
     case(
         "C",
-        "C",
+        "0",
         indoc!("
             [C - C major]
 
+                  1   2   3   4
             A  ||---|---|-3-|---|- C
             E o||---|---|---|---|- E
             C o||---|---|---|---|- C
@@ -140,38 +400,28 @@ fn test_chart(chord: &str, chart: &'static str) -> Result<(), Box<dyn Error + 's
         ")
     ),
     case(
-        "D",
-        "D",
-        indoc!("
-            [D - D major]
-
-            B   ||---|---|-3-|---|- D
-            F# o||---|---|---|---|- F#
-            D  o||---|---|---|---|- D
-            A  o||---|---|---|---|- A
-        ")
-    ),
-    case(
-        "G",
-        "G",
+        "C",
+        "1",
         indoc!("
-            [G - G major]
+            [C - C major]
 
-            E  ||---|---|-3-|---|- G
-            B o||---|---|---|---|- B
-            G o||---|---|---|---|- G
-            D o||---|---|---|---|- D
+                  3   4   5   6
+            A  -|-1-|---|---|---|- C
+            E  -|-1-|---|---|---|- G
+            C  -|---|-2-|---|---|- E
+            G  -|---|---|-3-|---|- C
+                  3
         ")
     ),
 )]
-fn test_tuning(
+fn test_fret_numbers(
     chord: &str,
-    tuning: &str,
+    min_fret: &str,
     chart: &'static str,
 ) -> Result<(), Box<dyn Error + 'static>> {
     let mut cmd = Command::cargo_bin("ukebox")?;
-    cmd.arg("chart");
-    cmd.arg("--tuning").arg(tuning);
+    cmd.arg("chart").arg("--fret-numbers");
+    cmd.arg("--min-fret").arg(min_fret);
     cmd.arg(chord);
     cmd.assert().success().stdout(format!("{chart}\n"));
 
@@ -184,15 +434,14 @@ fn test_tuning(
     chart,
     case(
         "C",
-        "1",
+        "0",
         indoc!("
             [C - C major]
 
-            A  -|-1-|---|---|---|- C
-            E  -|-1-|---|---|---|- G
-            C  -|---|-2-|---|---|- E
-            G  -|---|---|-3-|---|- C
-                  3
+            A  ||---|---|-3-|---|---|---|- C
+            E o||---|---|---|---|---|---|- E
+            C o||---|---|---|---|---|---|- C
+            G o||---|---|---|---|---|---|- G
         ")
     ),
     case(
@@ -201,34 +450,20 @@ fn test_tuning(
         indoc!("
             [C - C major]
 
-            A  -|-1-|---|---|---|- C
-            E  -|-1-|---|---|---|- G
-            C  -|---|-2-|---|---|- E
-            G  -|---|---|-3-|---|- C
-                  3
-        ")
-    ),
-    case(
-        "C",
-        "10",
-        indoc!("
-            [C - C major]
-
-            A  -|-1-|---|---|---|- G
-            E  -|---|---|-4-|---|- E
-            C  -|---|---|-3-|---|- C
-            G  -|---|---|-2-|---|- G
-                 10
+            A  ||---|---|-1-|---|---|---|- C
+            E  ||---|---|-1-|---|---|---|- G
+            C  ||---|---|---|-2-|---|---|- E
+            G  ||---|---|---|---|-3-|---|- C
         ")
     ),
 )]
-fn test_min_fret(
+fn test_chart_width(
     chord: &str,
     min_fret: &str,
     chart: &'static str,
 ) -> Result<(), Box<dyn Error + 'static>> {
     let mut cmd = Command::cargo_bin("ukebox")?;
-    cmd.arg("chart");
+    cmd.arg("chart").arg("--chart-width").arg("6");
     cmd.arg("--min-fret").arg(min_fret);
     cmd.arg(chord);
     cmd.assert().success().stdout(format!("{chart}\n"));
@@ -236,274 +471,1802 @@ fn test_min_fret(
     Ok(())
 }
 
+#[test]
+fn test_chart_width_too_small() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--chart-width").arg("2");
+    cmd.arg("--min-fret").arg("3");
+    cmd.arg("C");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "chart width 2 is too small for a chord spanning 3 frets",
+    ));
+
+    Ok(())
+}
+
 #[rstest(
-    chord,
-    max_span,
+    chords,
     chart,
     case(
-        "C#",
-        "3",
+        &["C", "Am"],
         indoc!("
-            [C# - C# major]
+            [C - C major]
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+
+            [Am - A minor]
+
+            A o||---|---|---|---|- A
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G  ||---|-2-|---|---|- A
 
-            A  -|-1-|---|---|---|- C#
-            E  -|-1-|---|---|---|- G#
-            C  -|---|-2-|---|---|- F
-            G  -|---|---|-3-|---|- C#
-                  4
         ")
     ),
     case(
-        "C#",
-        "5",
+        &["C", "C#"],
         indoc!("
+            [C - C major]
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+
             [C# - C# major]
 
-            A  ||---|---|---|-4-|---|- C#
-            E  ||-1-|---|---|---|---|- F
-            C  ||-1-|---|---|---|---|- C#
-            G  ||-1-|---|---|---|---|- G#
+            No matching chord voicing was found
         ")
     ),
 )]
-fn test_max_span(
-    chord: &str,
-    max_span: &str,
+fn test_chart_multiple_chords(
+    chords: &[&str],
     chart: &'static str,
 ) -> Result<(), Box<dyn Error + 'static>> {
     let mut cmd = Command::cargo_bin("ukebox")?;
-    cmd.arg("chart");
-    cmd.arg("--max-span").arg(max_span);
-    cmd.arg(chord);
-    cmd.assert().success().stdout(format!("{chart}\n"));
+    cmd.arg("chart").arg("--max-fret").arg("3");
+    cmd.args(chords);
+    cmd.assert().success().stdout(chart);
 
     Ok(())
 }
 
 #[rstest(
     chord,
-    semitones,
+    simplify,
     chart,
+    case("Cmaj9", false, "No matching chord voicing was found\n"),
     case(
-        "C",
-        "0",
+        "Cmaj9",
+        true,
         indoc!("
-            [C - C major]
+            [Cmaj7 - C major 7th]
 
-            A  ||---|---|-3-|---|- C
+            A  ||---|-2-|---|---|- B
             E o||---|---|---|---|- E
             C o||---|---|---|---|- C
             G o||---|---|---|---|- G
-        ")
-    ),
-    case(
-        "C",
-        "+1",
-        indoc!("
-            [C# - C# major]
 
-            A  ||---|---|---|-4-|- C#
-            E  ||-1-|---|---|---|- F
-            C  ||-1-|---|---|---|- C#
-            G  ||-1-|---|---|---|- G#
         ")
     ),
+    // Chords that can be played are not simplified.
     case(
-        "C",
-        "1",
+        "C9",
+        true,
         indoc!("
-            [C# - C# major]
+            [C9 - C dominant 9th]
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C  ||---|-1-|---|---|- D
+            G  ||---|---|-2-|---|- Bb
 
-            A  ||---|---|---|-4-|- C#
-            E  ||-1-|---|---|---|- F
-            C  ||-1-|---|---|---|- C#
-            G  ||-1-|---|---|---|- G#
         ")
     ),
-    case(
-        "D",
-        "-1",
-        indoc!("
-            [Db - Db major]
+)]
+fn test_chart_simplify(
+    chord: &str,
+    simplify: bool,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--max-fret").arg("3");
+    if simplify {
+        cmd.arg("--simplify");
+    }
+    cmd.arg(chord);
+    cmd.assert().success().stdout(chart);
 
-            A  ||---|---|---|-4-|- Db
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    option,
+    output,
+    case(
+        "E",
+        ("--max-span", "1"),
+        "No matching chord voicing was found\nTry --max-span 3\n"
+    ),
+    case(
+        "C#",
+        ("--max-fret", "3"),
+        "No matching chord voicing was found\nTry --max-fret 4\n"
+    ),
+    case("C", ("--min-fret", "20"), "No matching chord voicing was found\n"),
+    // --max-span and --max-fret cannot be combined with --position.
+    case(
+        "D",
+        ("--position", "3"),
+        "No matching chord voicing was found\nTry --position 2\n"
+    ),
+    case(
+        "D",
+        ("--position", "10"),
+        "No matching chord voicing was found\nTry --position 9\n"
+    ),
+)]
+fn test_chart_suggest(
+    chord: &str,
+    option: (&str, &str),
+    output: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--suggest");
+    cmd.arg(option.0).arg(option.1);
+    cmd.arg(chord);
+    cmd.assert().success().stdout(output);
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    position,
+    chart,
+    case(
+        "Am",
+        "2",
+        indoc!("
+            [Am - A minor]
+
+            A  -|---|-2-|---|---|- C
+            E  -|---|---|---|-4-|- A
+            C  -|---|---|-3-|---|- E
+            G  -|-1-|---|---|---|- A
+                  2
+
+        ")
+    ),
+    // The open-position voicing of C is excluded.
+    case(
+        "C",
+        "5",
+        indoc!("
+            [C - C major]
+
+            A  -|---|---|-3-|---|- E
+            E  -|---|---|---|-4-|- C
+            C  -|---|---|-2-|---|- G
+            G  -|-1-|---|---|---|- C
+                  5
+
+        ")
+    ),
+)]
+fn test_chart_position(
+    chord: &str,
+    position: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--position").arg(position).arg(chord);
+    cmd.assert().success().stdout(chart);
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    chart,
+    case(
+        "C",
+        indoc!("
+            [C - C major]
+
+            Doubled: C
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+
+        ")
+    ),
+    case(
+        "G",
+        indoc!("
+            [G - G major]
+
+            Doubled: G
+
+            A  ||---|-2-|---|---|- B
+            E  ||---|---|-3-|---|- G
+            C  ||---|-1-|---|---|- D
+            G o||---|---|---|---|- G
+
+        ")
+    ),
+)]
+fn test_chart_prefer_root_doubling(
+    chord: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--prefer-root-doubling").arg(chord);
+    cmd.assert().success().stdout(chart);
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    chart,
+    // C 0003 is already in root position (the open C string sounds lowest).
+    case(
+        "C",
+        indoc!("
+            [C - C major]
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+
+        ")
+    ),
+    // G 0232 has D in the bass, so a voicing with G on the C string is shown instead.
+    case(
+        "G",
+        indoc!("
+            [G - G major]
+
+            A  -|---|-2-|---|---|- D
+            E  -|---|---|---|-4-|- B
+            C  -|---|---|---|-3-|- G
+            G  -|-1-|---|---|---|- B
+                  4
+
+        ")
+    ),
+)]
+fn test_chart_only_root_position(
+    chord: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--only-root-position").arg(chord);
+    cmd.assert().success().stdout(chart);
+
+    Ok(())
+}
+
+#[rstest(
+    duplicate_notes,
+    chart,
+    case(
+        "allow",
+        indoc!("
+            [D - D major]
+
+            A o||---|---|---|---|- A
+            E  ||---|-1-|---|---|- F#
+            C  ||---|-1-|---|---|- D
+            G  ||---|-1-|---|---|- A
+
+        ")
+    ),
+    // 2220 plays A4 on both the G and the A string.
+    case(
+        "deny",
+        indoc!("
+            [D - D major]
+
+            A  -|---|---|---|-4-|- D
+            E  -|-1-|---|---|---|- F#
+            C  -|-1-|---|---|---|- D
+            G  -|-1-|---|---|---|- A
+                  2
+
+        ")
+    ),
+)]
+fn test_chart_duplicate_notes(
+    duplicate_notes: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--duplicate-notes")
+        .arg(duplicate_notes)
+        .arg("D");
+    cmd.assert().success().stdout(chart);
+
+    Ok(())
+}
+
+#[rstest(
+    best,
+    chart,
+    // The lowest voicing of Fmaj7 needs all four fingers.
+    case(
+        false,
+        indoc!("
+            [Fmaj7 - F major 7th]
+
+            A  ||---|---|-3-|---|- C
             E  ||-1-|---|---|---|- F
-            C  ||-1-|---|---|---|- Db
-            G  ||-1-|---|---|---|- Ab
+            C  ||---|---|---|-4-|- E
+            G  ||---|-2-|---|---|- A
+
+        ")
+    ),
+    // With --best, a voicing with two open strings is chosen instead.
+    case(
+        true,
+        indoc!("
+            [Fmaj7 - F major 7th]
+
+            A o||---|---|---|---|- A
+            E o||---|---|---|---|- E
+            C  -|-2-|---|---|---|- F
+            G  -|-1-|---|---|---|- C
+                  5
+
+        ")
+    ),
+)]
+fn test_chart_best(best: bool, chart: &'static str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    if best {
+        cmd.arg("--best");
+    }
+    cmd.arg("Fmaj7");
+    cmd.assert().success().stdout(chart);
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_notes() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--notes").arg("C E G Bb");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [C7 - C dominant 7th]
+
+        A  ||-1-|---|---|---|- Bb
+        E o||---|---|---|---|- E
+        C o||---|---|---|---|- C
+        G o||---|---|---|---|- G
+
+        "
+    ));
+
+    Ok(())
+}
+
+#[rstest(
+    notes,
+    error,
+    case(
+        "C C# D",
+        "notes 'C C# D' do not form a recognized chord: no matching chord type found"
+    ),
+    case(
+        "C",
+        "notes 'C' do not form a recognized chord: not enough notes to determine a chord type"
+    ),
+    case(
+        "C H G",
+        "notes 'C H G' do not form a recognized chord: could not parse note name 'H'"
+    )
+)]
+fn test_chart_notes_fail(notes: &str, error: &str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--notes").arg(notes);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(error));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_diagram_style_dot() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--diagram-style").arg("dot").arg("G");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [G - G major]
+
+        A  ||---+-●-+---+---+- B
+        E  ||---+---+-●-+---+- G
+        C  ||---+-●-+---+---+- D
+        G ○||---+---+---+---+- G
+
+        "
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_diagram_style_dot_ascii_only() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("--ascii-only")
+        .arg("chart")
+        .arg("--diagram-style")
+        .arg("dot")
+        .arg("G");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [G - G major]
+
+        A  ||---|-2-|---|---|- B
+        E  ||---|---|-3-|---|- G
+        C  ||---|-1-|---|---|- D
+        G o||---|---|---|---|- G
+
+        "
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_without_labels() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--no-string-labels")
+        .arg("--no-note-labels")
+        .arg("C");
+    cmd.assert().success().stdout(concat!(
+        "[C - C major]\n",
+        "\n",
+        " ||---|---|-3-|---|\n",
+        "o||---|---|---|---|\n",
+        "o||---|---|---|---|\n",
+        "o||---|---|---|---|\n",
+        "\n",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_voice_lead_max_jump() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("voice-lead").arg("Cmaj9 Abmaj13");
+    cmd.assert().success().stdout(indoc!(
+        "
+        No matching chord voicing sequence was found
+        Try a larger --max-jump (currently 10)
+    "
+    ));
+
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("voice-lead")
+        .arg("--max-jump")
+        .arg("11")
+        .arg("Cmaj9 Abmaj13");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [Cmaj9 - C major 9th]
+
+        A  ||---|---|-2-|---|- C
+        E o||---|---|---|---|- E
+        C  ||---|-1-|---|---|- D
+        G  ||---|---|---|-3-|- B
+
+        [Abmaj13 - Ab major 13th]
+
+        A  -|-1-|---|---|---|- C
+        E  -|---|-2-|---|---|- Ab
+        C  -|---|---|-3-|---|- F
+        G o||---|---|---|---|- G
+              3
+
+    "
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_voice_lead_explain() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("voice-lead").arg("--explain").arg("C Am F G");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::ends_with(indoc!(
+            "
+        C -> Am: 2 semitones, 1 finger move
+        Am -> F: 1 semitone, 1 finger move
+        F -> G: 7 semitones, 6 finger moves
+        Total: 10 semitones, 8 finger moves
+
+    "
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn test_voice_lead_beam_width() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("voice-lead")
+        .arg("--beam-width")
+        .arg("3")
+        .arg("C Am Dm7 G7 Em A7 Dm G13");
+    let output = cmd.output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert_eq!(stdout.matches('[').count(), 8);
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_tsv() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--format")
+        .arg("tsv")
+        .arg("--min-fret")
+        .arg("3")
+        .arg("C")
+        .arg("Cm7");
+    cmd.assert().success().stdout(concat!(
+        "chord\ttuning\tfrets\tfingers\tbase_fret\tbarre\topen_string\tnotes\n",
+        "C\tC\t5 4 3 3\t3 2 1 1\t3\ttrue\tfalse\tC E G C\n",
+        "Cm7\tC\t3 3 3 3\t1 1 1 1\t1\ttrue\tfalse\tBb Eb G C\n",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_sort_pitch() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--format")
+        .arg("tsv")
+        .arg("--all")
+        .arg("--max-fret")
+        .arg("7")
+        .arg("--sort")
+        .arg("pitch")
+        .arg("C");
+    // 0007 has C4 in the bass, so it comes right after 0003.
+    cmd.assert().success().stdout(concat!(
+        "chord\ttuning\tfrets\tfingers\tbase_fret\tbarre\topen_string\tnotes\n",
+        "C\tC\t0 0 0 3\t0 0 0 3\t1\tfalse\ttrue\tG C E C\n",
+        "C\tC\t0 0 0 7\t0 0 0 1\t7\tfalse\ttrue\tG C E E\n",
+        "C\tC\t0 4 0 3\t0 2 0 1\t1\tfalse\ttrue\tG E E C\n",
+        "C\tC\t0 4 3 3\t0 2 1 1\t1\ttrue\ttrue\tG E G C\n",
+        "C\tC\t5 4 3 3\t3 2 1 1\t3\ttrue\tfalse\tC E G C\n",
+        "C\tC\t5 7 0 7\t1 3 0 4\t5\tfalse\ttrue\tC G E E\n",
+    ));
+
+    Ok(())
+}
+
+#[rstest(opt, case("--min-fret"), case("--max-fret"), case("--max-span"))]
+fn test_chart_position_conflict(opt: &str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--position")
+        .arg("5")
+        .arg(opt)
+        .arg("3")
+        .arg("C");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    tuning,
+    chart,
+    case(
+        "C",
+        "C",
+        indoc!("
+            [C - C major]
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+        ")
+    ),
+    case(
+        "D",
+        "D",
+        indoc!("
+            [D - D major]
+
+            B   ||---|---|-3-|---|- D
+            F# o||---|---|---|---|- F#
+            D  o||---|---|---|---|- D
+            A  o||---|---|---|---|- A
+        ")
+    ),
+    case(
+        "G",
+        "G",
+        indoc!("
+            [G - G major]
+
+            E  ||---|---|-3-|---|- G
+            B o||---|---|---|---|- B
+            G o||---|---|---|---|- G
+            D o||---|---|---|---|- D
+        ")
+    ),
+)]
+fn test_tuning(
+    chord: &str,
+    tuning: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    cmd.arg("--tuning").arg(tuning);
+    cmd.arg(chord);
+    cmd.assert().success().stdout(format!("{chart}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    min_fret,
+    chart,
+    case(
+        "C",
+        "1",
+        indoc!("
+            [C - C major]
+
+            A  -|-1-|---|---|---|- C
+            E  -|-1-|---|---|---|- G
+            C  -|---|-2-|---|---|- E
+            G  -|---|---|-3-|---|- C
+                  3
+        ")
+    ),
+    case(
+        "C",
+        "3",
+        indoc!("
+            [C - C major]
+
+            A  -|-1-|---|---|---|- C
+            E  -|-1-|---|---|---|- G
+            C  -|---|-2-|---|---|- E
+            G  -|---|---|-3-|---|- C
+                  3
+        ")
+    ),
+    case(
+        "C",
+        "10",
+        indoc!("
+            [C - C major]
+
+            A  -|-1-|---|---|---|- G
+            E  -|---|---|-4-|---|- E
+            C  -|---|---|-3-|---|- C
+            G  -|---|---|-2-|---|- G
+                 10
+        ")
+    ),
+)]
+fn test_min_fret(
+    chord: &str,
+    min_fret: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    cmd.arg("--min-fret").arg(min_fret);
+    cmd.arg(chord);
+    cmd.assert().success().stdout(format!("{chart}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    max_span,
+    chart,
+    case(
+        "C#",
+        "3",
+        indoc!("
+            [C# - C# major]
+
+            A  -|-1-|---|---|---|- C#
+            E  -|-1-|---|---|---|- G#
+            C  -|---|-2-|---|---|- F
+            G  -|---|---|-3-|---|- C#
+                  4
+        ")
+    ),
+    case(
+        "C#",
+        "5",
+        indoc!("
+            [C# - C# major]
+
+            A  ||---|---|---|-4-|---|- C#
+            E  ||-1-|---|---|---|---|- F
+            C  ||-1-|---|---|---|---|- C#
+            G  ||-1-|---|---|---|---|- G#
+        ")
+    ),
+)]
+fn test_max_span(
+    chord: &str,
+    max_span: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    cmd.arg("--max-span").arg(max_span);
+    cmd.arg(chord);
+    cmd.assert().success().stdout(format!("{chart}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    semitones,
+    chart,
+    case(
+        "C",
+        "0",
+        indoc!("
+            [C - C major]
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+        ")
+    ),
+    case(
+        "C",
+        "+1",
+        indoc!("
+            [C# - C# major]
+
+            A  ||---|---|---|-4-|- C#
+            E  ||-1-|---|---|---|- F
+            C  ||-1-|---|---|---|- C#
+            G  ||-1-|---|---|---|- G#
+        ")
+    ),
+    case(
+        "C",
+        "1",
+        indoc!("
+            [C# - C# major]
+
+            A  ||---|---|---|-4-|- C#
+            E  ||-1-|---|---|---|- F
+            C  ||-1-|---|---|---|- C#
+            G  ||-1-|---|---|---|- G#
+        ")
+    ),
+    case(
+        "D",
+        "-1",
+        indoc!("
+            [Db - Db major]
+
+            A  ||---|---|---|-4-|- Db
+            E  ||-1-|---|---|---|- F
+            C  ||-1-|---|---|---|- Db
+            G  ||-1-|---|---|---|- Ab
+        ")
+    ),
+)]
+fn test_transpose(
+    chord: &str,
+    semitones: &str,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart");
+    cmd.arg("--transpose").arg(semitones);
+    cmd.arg(chord);
+    cmd.assert().success().stdout(format!("{chart}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    min_fret,
+    max_fret,
+    chart,
+    case(
+        "C#",
+        None,
+        None,
+        indoc!("
+            [C# - C# major]
+
+            A  ||---|---|---|-4-|- C#
+            E  ||-1-|---|---|---|- F
+            C  ||-1-|---|---|---|- C#
+            G  ||-1-|---|---|---|- G#
+
+            A  -|-1-|---|---|---|- C#
+            E  -|-1-|---|---|---|- G#
+            C  -|---|-2-|---|---|- F
+            G  -|---|---|-3-|---|- C#
+                  4
+
+            A  -|---|---|-3-|---|- F
+            E  -|---|---|---|-4-|- C#
+            C  -|---|---|-2-|---|- G#
+            G  -|-1-|---|---|---|- C#
+                  6
+
+            A  -|-1-|---|---|---|- F
+            E  -|---|-2-|---|---|- C#
+            C  -|-1-|---|---|---|- G#
+            G  -|---|---|-3-|---|- F
+                  8
+
+            A  -|---|---|---|-4-|- G#
+            E  -|---|-2-|---|---|- C#
+            C  -|-1-|---|---|---|- G#
+            G  -|---|---|-3-|---|- F
+                  8
+        ")
+    ),
+    case(
+        "C#",
+        Some("5"),
+        None,
+        indoc!("
+            [C# - C# major]
+
+            A  -|---|---|-3-|---|- F
+            E  -|---|---|---|-4-|- C#
+            C  -|---|---|-2-|---|- G#
+            G  -|-1-|---|---|---|- C#
+                  6
+
+            A  -|-1-|---|---|---|- F
+            E  -|---|-2-|---|---|- C#
+            C  -|-1-|---|---|---|- G#
+            G  -|---|---|-3-|---|- F
+                  8
+
+            A  -|---|---|---|-4-|- G#
+            E  -|---|-2-|---|---|- C#
+            C  -|-1-|---|---|---|- G#
+            G  -|---|---|-3-|---|- F
+                  8
+        ")
+    ),
+    case(
+        "C#",
+        None,
+        Some("10"),
+        indoc!("
+            [C# - C# major]
+
+            A  ||---|---|---|-4-|- C#
+            E  ||-1-|---|---|---|- F
+            C  ||-1-|---|---|---|- C#
+            G  ||-1-|---|---|---|- G#
+
+            A  -|-1-|---|---|---|- C#
+            E  -|-1-|---|---|---|- G#
+            C  -|---|-2-|---|---|- F
+            G  -|---|---|-3-|---|- C#
+                  4
+
+            A  -|---|---|-3-|---|- F
+            E  -|---|---|---|-4-|- C#
+            C  -|---|---|-2-|---|- G#
+            G  -|-1-|---|---|---|- C#
+                  6
+
+            A  -|-1-|---|---|---|- F
+            E  -|---|-2-|---|---|- C#
+            C  -|-1-|---|---|---|- G#
+            G  -|---|---|-3-|---|- F
+                  8
+        ")
+    ),
+    case(
+        "C#",
+        Some("5"),
+        Some("10"),
+        indoc!("
+            [C# - C# major]
+
+            A  -|---|---|-3-|---|- F
+            E  -|---|---|---|-4-|- C#
+            C  -|---|---|-2-|---|- G#
+            G  -|-1-|---|---|---|- C#
+                  6
+
+            A  -|-1-|---|---|---|- F
+            E  -|---|-2-|---|---|- C#
+            C  -|-1-|---|---|---|- G#
+            G  -|---|---|-3-|---|- F
+                  8
+        ")
+    ),
+)]
+fn test_all(
+    chord: &str,
+    min_fret: Option<&str>,
+    max_fret: Option<&str>,
+    chart: &'static str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--all");
+
+    if let Some(fret) = min_fret {
+        cmd.arg("--min-fret").arg(fret);
+    }
+
+    if let Some(fret) = max_fret {
+        cmd.arg("--max-fret").arg(fret);
+    }
+
+    cmd.arg(chord);
+    cmd.assert().success().stdout(format!("{chart}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chart,
+    names,
+    case("0000", "C6 - C major 6th\nAm7 - A minor 7th"),
+    case("0003", "C - C major"),
+    case("0013", "Csus4 - C suspended 4th\nFsus2 - F suspended 2nd"),
+    case("10 10 10 10", "A#6 - A# major 6th\nGm7 - G minor 7th"),
+    case("7604", "Dmaj9 - D major 9th"),
+    case("1304", "Emaj13 - E major 13th"),
+    case("x210", "Dm - D minor"),
+    case("2x13", "F - F major"),
+    case("3213", "A#add9 - A# added 9th\nC11(no3) - C dominant 11th (no 3rd)"),
+    case(
+        "2233",
+        "D7sus4 - D dominant 7th suspended 4th\nAm11 - A minor 11th\nquartal (A D G C)"
+    ),
+    case(
+        "9 9 10 10",
+        "A7sus4 - A dominant 7th suspended 4th\nEm11 - E minor 11th\nquartal (E A D G)"
+    ),
+    case("2323", "Cdim7 - C diminished 7th (= D#dim7 = F#dim7 = Adim7)"),
+    case("1212", "Ddim7 - D diminished 7th (= Fdim7 = G#dim7 = Bdim7)"),
+    case("1003", "Caug - C augmented (= Eaug = G#aug)"),
+    case("5443", "Caug - C augmented (= Eaug = G#aug)"),
+    case("2110", "C#aug - C# augmented (= Faug = Aaug)")
+)]
+fn test_name(chart: &str, names: &'static str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg(chart);
+    cmd.assert().success().stdout(format!("{names}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chart,
+    output,
+    case(
+        "0013",
+        indoc!("
+            [Csus4 - C suspended 4th]
+
+            A  C   P1
+            E  F   P4
+            C  C   P1
+            G  G   P5
+
+            [Fsus2 - F suspended 2nd]
+
+            A  C   P5
+            E  F   P1
+            C  C   P5
+            G  G   M2
+        ")
+    ),
+    case(
+        "0003",
+        indoc!("
+            [C - C major]
+
+            A  C   P1
+            E  E   M3
+            C  C   P1
+            G  G   P5
+        ")
+    ),
+    case(
+        "x232",
+        indoc!("
+            [G - G major]
+
+            A  B   M3
+            E  G   P1
+            C  D   P5
+            G  x
+        ")
+    ),
+    // Strings are labeled with flats in flat keys.
+    case(
+        "0231",
+        indoc!("
+            [Gm - G minor]
+
+            A  Bb  m3
+            E  G   P1
+            C  D   P5
+            G  G   P1
+        ")
+    ),
+    case("1234", "No matching chord was found"),
+)]
+fn test_name_all(chart: &str, output: &'static str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--all").arg(chart);
+    cmd.assert().success().stdout(format!("{output}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chart,
+    option,
+    count,
+    case("2323", None, 4),
+    case("2323", Some("--no-duplicate-names"), 1),
+    case("0000", None, 2),
+    case("0000", Some("--no-duplicate-names"), 1),
+    case("0003", Some("--no-duplicate-names"), 1)
+)]
+fn test_name_no_duplicate_names(
+    chart: &str,
+    option: Option<&str>,
+    count: usize,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--all");
+    if let Some(option) = option {
+        cmd.arg(option);
+    }
+    let output = cmd.arg(chart).output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert_eq!(stdout.matches('[').count(), count);
+
+    Ok(())
+}
+
+#[test]
+fn test_name_no_duplicate_names_text() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--no-duplicate-names").arg("0000");
+    cmd.assert().success().stdout("C6 - C major 6th\n");
+
+    Ok(())
+}
+
+#[rstest(
+    chart,
+    offset,
+    title,
+    case("0003", "0", "C - C major"),
+    case("0003", "-1", "B - B major"),
+    case("0003", "+2", "D - D major"),
+    case("2220", "-2", "C - C major")
+)]
+fn test_name_tuning_offset(
+    chart: &str,
+    offset: &str,
+    title: &str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name")
+        .arg("--tuning-offset")
+        .arg(offset)
+        .arg(chart);
+    cmd.assert().success().stdout(format!("{title}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    chart,
+    semitones,
+    title,
+    case("0003", "0", "C - C major"),
+    case("0003", "2", "D - D major"),
+    case("0003", "+2", "D - D major"),
+    case("0003", "-1", "B - B major"),
+    case("2010", "12", "F - F major")
+)]
+fn test_name_transpose(
+    chart: &str,
+    semitones: &str,
+    title: &str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg(chart).arg("--transpose").arg(semitones);
+    cmd.assert().success().stdout(format!("{title}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    args,
+    chart,
+    case(
+        vec!["chart", "--highlight-root", "C"],
+        indoc!("
+            [C - C major]
+
+            A  ||---|---|-3-|---|- C R
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C R
+            G o||---|---|---|---|- G
+        ")
+    ),
+    case(
+        vec!["chart", "--highlight-root", "--min-fret", "3", "C"],
+        indoc!("
+            [C - C major]
+
+            A  -|-1-|---|---|---|- C R
+            E  -|-1-|---|---|---|- G
+            C  -|---|-2-|---|---|- E
+            G  -|---|---|-3-|---|- C R
+                  3
+        ")
+    ),
+    case(
+        vec!["chart", "C"],
+        indoc!("
+            [C - C major]
+
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+        ")
+    ),
+)]
+fn test_highlight_root(args: Vec<&str>, chart: &str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.args(args);
+    cmd.assert().success().stdout(format!("{chart}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    accidentals,
+    chart,
+    names,
+    case(None, "3211", "A# - A# major"),
+    case(Some("sharp"), "3211", "A# - A# major"),
+    case(Some("flat"), "3211", "Bb - Bb major"),
+    case(Some("flat"), "0003", "C - C major"),
+    case(
+        Some("flat"),
+        "2323",
+        "Cdim7 - C diminished 7th (= Ebdim7 = Gbdim7 = Adim7)"
+    )
+)]
+fn test_name_accidentals(
+    accidentals: Option<&str>,
+    chart: &str,
+    names: &str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name");
+    if let Some(accidentals) = accidentals {
+        cmd.arg("--accidentals").arg(accidentals);
+    }
+    cmd.arg(chart);
+    cmd.assert().success().stdout(format!("{names}\n"));
+
+    Ok(())
+}
+
+#[rstest(
+    args,
+    summary,
+    case(vec!["--all", "--max-fret", "5", "C"], "Found 4 voicings (3 open, 2 barre)"),
+    case(vec!["--all", "--max-fret", "5", "--columns", "2", "C"], "Found 4 voicings (3 open, 2 barre)"),
+    case(vec!["--all", "--max-fret", "3", "C"], "Found 1 voicing (1 open, 0 barre)"),
+    case(vec!["C"], "Found 1 voicing (1 open, 0 barre)"),
+    case(vec!["--min-fret", "3", "C"], "Found 1 voicing (0 open, 1 barre)")
+)]
+fn test_chart_summary(args: Vec<&str>, summary: &str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--summary").args(args);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::ends_with(format!("\n{summary}\n\n")));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_summary_not_found() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--summary")
+        .arg("--all")
+        .arg("--max-fret")
+        .arg("0")
+        .arg("F");
+    cmd.assert()
+        .success()
+        .stdout("No matching chord voicing was found\n");
+
+    Ok(())
+}
+
+#[rstest(
+    args,
+    chart,
+    case(
+        vec!["revoice", "0003", "--from", "C", "--to", "D"],
+        indoc!("
+            [C - C major]
+
+            B   ||-1-|---|---|---|- C
+            F#  ||-1-|---|---|---|- G
+            D   ||---|-2-|---|---|- E
+            A   ||---|---|-3-|---|- C
+        ")
+    ),
+    case(
+        vec!["revoice", "0003", "--to", "G"],
+        indoc!("
+            [C - C major]
+
+            E o||---|---|---|---|- E
+            B  ||-1-|---|---|---|- C
+            G o||---|---|---|---|- G
+            D  ||---|-2-|---|---|- E
+        ")
+    ),
+    case(
+        vec!["--tuning", "D", "revoice", "0003", "--to", "C"],
+        indoc!("
+            [D - D major]
+
+            A o||---|---|---|---|- A
+            E  ||---|-1-|---|---|- F#
+            C  ||---|-1-|---|---|- D
+            G  ||---|-1-|---|---|- A
+        ")
+    ),
+    case(vec!["revoice", "1234", "--to", "D"], "No matching chord was found"),
+)]
+fn test_revoice(args: Vec<&str>, chart: &str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.args(args);
+    cmd.assert().success().stdout(format!("{chart}\n"));
+
+    Ok(())
+}
+
+#[test]
+fn test_songsheet() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = assert_cmd::Command::cargo_bin("ukebox")?;
+    cmd.arg("songsheet").arg("-");
+    cmd.write_stdin("{title: Test}\n[C]Twinkle [Am]twinkle [F]little [G]star [C]\n[H7]how\n");
+    cmd.assert()
+        .success()
+        .stdout(indoc!(
+            "
+            [C]                         [Am]                        [F]                         [G]
+            A  ||---|---|-3-|---|- C    A o||---|---|---|---|- A    A o||---|---|---|---|- A    A  ||---|-2-|---|---|- B
+            E o||---|---|---|---|- E    E o||---|---|---|---|- E    E  ||-1-|---|---|---|- F    E  ||---|---|-3-|---|- G
+            C o||---|---|---|---|- C    C o||---|---|---|---|- C    C o||---|---|---|---|- C    C  ||---|-1-|---|---|- D
+            G o||---|---|---|---|- G    G  ||---|-2-|---|---|- A    G  ||---|-2-|---|---|- A    G o||---|---|---|---|- G
+
+        "
+        ))
+        .stderr(predicate::str::starts_with(
+            "H7: could not parse chord name 'H7'",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn test_songsheet_columns() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = assert_cmd::Command::cargo_bin("ukebox")?;
+    cmd.arg("songsheet").arg("--columns").arg("3").arg("-");
+    cmd.write_stdin("[C] [Am] [F] [G]");
+    let output = cmd.output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let titles: Vec<&str> = stdout.lines().filter(|l| l.starts_with('[')).collect();
+    assert_eq!(titles.len(), 2);
+    assert!(titles[0].starts_with("[C]"));
+    assert!(titles[1].starts_with("[G]"));
+
+    Ok(())
+}
+
+#[test]
+fn test_songsheet_no_chords() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = assert_cmd::Command::cargo_bin("ukebox")?;
+    cmd.arg("songsheet").arg("-");
+    cmd.write_stdin("{title: Test}\nNo chords here\n");
+    cmd.assert().success().stdout("No chords were found\n");
+
+    Ok(())
+}
+
+#[test]
+fn test_name_batch_stdin() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = assert_cmd::Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--file").arg("-");
+    cmd.write_stdin("0003\n\n2323\nfoo\n x210 \n1234\n");
+    cmd.assert()
+        .success()
+        .stdout(indoc!(
+            "
+            0003: C - C major
+            2323: Cdim7 - C diminished 7th (= D#dim7 = F#dim7 = Adim7)
+            x210: Dm - D minor
+            1234: No matching chord was found
+        "
+        ))
+        .stderr(predicate::str::starts_with(
+            "foo: fret pattern has wrong format",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn test_name_batch_file() -> Result<(), Box<dyn Error + 'static>> {
+    let path = std::env::temp_dir().join("ukebox_test_name_batch_file.txt");
+    std::fs::write(&path, "0013\n2220\n")?;
+
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--file").arg(&path);
+    cmd.assert().success().stdout(indoc!(
+        "
+        0013: Csus4 - C suspended 4th
+        0013: Fsus2 - F suspended 2nd
+        2220: D - D major
+    "
+    ));
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_name_batch_json() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = assert_cmd::Command::cargo_bin("ukebox")?;
+    cmd.arg("name")
+        .arg("--file")
+        .arg("-")
+        .arg("--format")
+        .arg("json");
+    cmd.write_stdin("0003\nx210\n");
+    let output = cmd.output()?;
+    assert!(output.status.success());
+
+    let json: Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json[0]["input"], "0003");
+    assert_eq!(json[0]["chords"][0]["name"], "C");
+    assert_eq!(json[1]["input"], "x210");
+    assert_eq!(json[1]["chords"][0]["name"], "Dm");
+
+    Ok(())
+}
+
+#[test]
+fn test_name_batch_missing_file() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--file").arg("does_not_exist.txt");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "could not read 'does_not_exist.txt'",
+    ));
+
+    Ok(())
+}
+
+#[rstest(
+    chart,
+    reversed_chart,
+    case("0003", "3000"),
+    case("2220", "0222"),
+    case("x032", "230x"),
+    case("0233", "3320")
+)]
+fn test_name_reverse_strings(
+    chart: &str,
+    reversed_chart: &str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg(chart);
+    let output = cmd.output()?;
+
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--reverse-strings").arg(reversed_chart);
+    cmd.assert().success().stdout(output.stdout);
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_reverse_strings() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--reverse-strings").arg("C");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [C - C major]
+
+        G o||---|---|---|---|- G
+        C o||---|---|---|---|- C
+        E o||---|---|---|---|- E
+        A  ||---|---|-3-|---|- C
+
+    "
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_near() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("C").arg("--near").arg("0232");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [C - C major]
+
+        A  ||---|---|-1-|---|- C
+        E  ||---|---|-1-|---|- G
+        C  ||---|---|---|-2-|- E
+        G o||---|---|---|---|- G
+
+    "
+    ));
+
+    Ok(())
+}
+
+#[rstest(
+    chord,
+    header,
+    case("C", "[C - C major (P1 M3 P5)]"),
+    case("Cm7", "[Cm7 - C minor 7th (P1 m3 P5 m7)]"),
+    case("C13", "[C13 - C dominant 13th (P1 M3 P5 m7 M9 P11 M13)]")
+)]
+fn test_chart_show_formula(chord: &str, header: &str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--show-formula").arg(chord);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with(format!("{header}\n")));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_tab() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("--format").arg("tab").arg("C");
+    cmd.assert().success().stdout(indoc!(
+        "
+        A|-3-|
+        E|-0-|
+        C|-0-|
+        G|-0-|
+    "
+    ));
+
+    Ok(())
+}
+
+#[rstest(
+    order,
+    tab,
+    case(
+        "high-first",
+        indoc!("
+            A|-3-0-0-|
+            E|-0-0-1-|
+            C|-0-0-0-|
+            G|-0-2-2-|
+        ")
+    ),
+    case(
+        "low-first",
+        indoc!("
+            G|-0-2-2-|
+            C|-0-0-0-|
+            E|-0-0-1-|
+            A|-3-0-0-|
         ")
     ),
 )]
-fn test_transpose(
-    chord: &str,
-    semitones: &str,
-    chart: &'static str,
+fn test_chart_tab_string_order(
+    order: &str,
+    tab: &'static str,
 ) -> Result<(), Box<dyn Error + 'static>> {
     let mut cmd = Command::cargo_bin("ukebox")?;
-    cmd.arg("chart");
-    cmd.arg("--transpose").arg(semitones);
-    cmd.arg(chord);
-    cmd.assert().success().stdout(format!("{chart}\n"));
+    cmd.arg("chart")
+        .arg("--format")
+        .arg("tab")
+        .arg("--tab-string-order")
+        .arg(order)
+        .arg("C")
+        .arg("Am")
+        .arg("F");
+    cmd.assert().success().stdout(tab);
 
     Ok(())
 }
 
-#[rstest(
-    chord,
-    min_fret,
-    max_fret,
-    chart,
-    case(
-        "C#",
-        None,
-        None,
-        indoc!("
-            [C# - C# major]
+#[test]
+fn test_chart_tab_wide_frets() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--format")
+        .arg("tab")
+        .arg("--all")
+        .arg("--min-fret")
+        .arg("7")
+        .arg("--tuning-offset")
+        .arg("-1")
+        .arg("C");
+    cmd.assert().success().stdout(indoc!(
+        "
+        G#|-8--11-|
+        D#|-9--9--|
+        B |-8--8--|
+        F#|-10-10-|
+    "
+    ));
 
-            A  ||---|---|---|-4-|- C#
-            E  ||-1-|---|---|---|- F
-            C  ||-1-|---|---|---|- C#
-            G  ||-1-|---|---|---|- G#
+    Ok(())
+}
 
-            A  -|-1-|---|---|---|- C#
-            E  -|-1-|---|---|---|- G#
-            C  -|---|-2-|---|---|- F
-            G  -|---|---|-3-|---|- C#
-                  4
+#[test]
+fn test_chart_inlays() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("--inlays")
+        .arg("--min-fret")
+        .arg("5")
+        .arg("G");
+    // Not using indoc here as all lines start with a space.
+    cmd.assert().success().stdout(concat!(
+        "[G - G major]\n",
+        "\n",
+        "      *       *\n",
+        "A  -|-1-|---|---|---|- D\n",
+        "E  -|---|---|-4-|---|- B\n",
+        "C  -|---|---|-3-|---|- G\n",
+        "G  -|---|---|-2-|---|- D\n",
+        "      5\n",
+        "\n",
+    ));
 
-            A  -|---|---|-3-|---|- F
-            E  -|---|---|---|-4-|- C#
-            C  -|---|---|-2-|---|- G#
-            G  -|-1-|---|---|---|- C#
-                  6
+    Ok(())
+}
 
-            A  -|-1-|---|---|---|- F
-            E  -|---|-2-|---|---|- C#
-            C  -|-1-|---|---|---|- G#
-            G  -|---|---|-3-|---|- F
-                  8
+#[test]
+fn test_chart_prefer_shape() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("C").arg("--prefer-shape").arg("A");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [C - C major]
+
+        A  -|-1-|---|---|---|- C
+        E  -|-1-|---|---|---|- G
+        C  -|---|-2-|---|---|- E
+        G  -|---|---|-3-|---|- C
+              3
+
+    "
+    ));
 
-            A  -|---|---|---|-4-|- G#
-            E  -|---|-2-|---|---|- C#
-            C  -|-1-|---|---|---|- G#
-            G  -|---|---|-3-|---|- F
-                  8
-        ")
-    ),
-    case(
-        "C#",
-        Some("5"),
-        None,
-        indoc!("
-            [C# - C# major]
+    Ok(())
+}
 
-            A  -|---|---|-3-|---|- F
-            E  -|---|---|---|-4-|- C#
-            C  -|---|---|-2-|---|- G#
-            G  -|-1-|---|---|---|- C#
-                  6
+#[test]
+fn test_chart_tuning_offset() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart").arg("B").arg("--tuning-offset").arg("-1");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [B - B major]
 
-            A  -|-1-|---|---|---|- F
-            E  -|---|-2-|---|---|- C#
-            C  -|-1-|---|---|---|- G#
-            G  -|---|---|-3-|---|- F
-                  8
+        G#  ||---|---|-3-|---|- B
+        D# o||---|---|---|---|- D#
+        B  o||---|---|---|---|- B
+        F# o||---|---|---|---|- F#
 
-            A  -|---|---|---|-4-|- G#
-            E  -|---|-2-|---|---|- C#
-            C  -|-1-|---|---|---|- G#
-            G  -|---|---|-3-|---|- F
-                  8
-        ")
-    ),
-    case(
-        "C#",
-        None,
-        Some("10"),
-        indoc!("
-            [C# - C# major]
+    "
+    ));
 
-            A  ||---|---|---|-4-|- C#
-            E  ||-1-|---|---|---|- F
-            C  ||-1-|---|---|---|- C#
-            G  ||-1-|---|---|---|- G#
+    Ok(())
+}
 
-            A  -|-1-|---|---|---|- C#
-            E  -|-1-|---|---|---|- G#
-            C  -|---|-2-|---|---|- F
-            G  -|---|---|-3-|---|- C#
-                  4
+#[test]
+fn test_chart_tuning_offset_only_root_position() -> Result<(), Box<dyn Error + 'static>> {
+    // Tuned down a half step, the open C string plays B3, the lowest note of 0003.
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("chart")
+        .arg("B")
+        .arg("--tuning-offset")
+        .arg("-1")
+        .arg("--only-root-position");
+    cmd.assert().success().stdout(indoc!(
+        "
+        [B - B major]
+
+        G#  ||---|---|-3-|---|- B
+        D# o||---|---|---|---|- D#
+        B  o||---|---|---|---|- B
+        F# o||---|---|---|---|- F#
+
+    "
+    ));
 
-            A  -|---|---|-3-|---|- F
-            E  -|---|---|---|-4-|- C#
-            C  -|---|---|-2-|---|- G#
-            G  -|-1-|---|---|---|- C#
-                  6
+    Ok(())
+}
 
-            A  -|-1-|---|---|---|- F
-            E  -|---|-2-|---|---|- C#
-            C  -|-1-|---|---|---|- G#
-            G  -|---|---|-3-|---|- F
-                  8
+#[rstest(
+    chart,
+    explanation,
+    case(
+        "0000",
+        indoc!("
+            Root C: semitones 0 4 7 9 -> major 6th (required: P1 M3 M6, optional: P5)
+            Root E: semitones 0 3 5 8 -> no matching chord type found
+            Root G: semitones 0 2 5 9 -> no matching chord type found
+            Root A: semitones 0 3 7 10 -> minor 7th (required: P1 m3 m7, optional: P5)
+
+            C6 - C major 6th
+            Am7 - A minor 7th
         ")
     ),
     case(
-        "C#",
-        Some("5"),
-        Some("10"),
+        "0123",
         indoc!("
-            [C# - C# major]
-
-            A  -|---|---|-3-|---|- F
-            E  -|---|---|---|-4-|- C#
-            C  -|---|---|-2-|---|- G#
-            G  -|-1-|---|---|---|- C#
-                  6
+            Root C: semitones 0 1 6 7 -> no matching chord type found
+            Root C#: semitones 0 5 6 11 -> no matching chord type found
+            Root F#: semitones 0 1 6 7 -> no matching chord type found
+            Root G: semitones 0 5 6 11 -> no matching chord type found
 
-            A  -|-1-|---|---|---|- F
-            E  -|---|-2-|---|---|- C#
-            C  -|-1-|---|---|---|- G#
-            G  -|---|---|-3-|---|- F
-                  8
+            No matching chord was found
         ")
     ),
 )]
-fn test_all(
-    chord: &str,
-    min_fret: Option<&str>,
-    max_fret: Option<&str>,
-    chart: &'static str,
+fn test_name_verbose(
+    chart: &str,
+    explanation: &'static str,
 ) -> Result<(), Box<dyn Error + 'static>> {
     let mut cmd = Command::cargo_bin("ukebox")?;
-    cmd.arg("chart").arg("--all");
-
-    if let Some(fret) = min_fret {
-        cmd.arg("--min-fret").arg(fret);
-    }
+    cmd.arg("name").arg("--verbose").arg(chart);
+    cmd.assert().success().stdout(explanation);
 
-    if let Some(fret) = max_fret {
-        cmd.arg("--max-fret").arg(fret);
-    }
+    Ok(())
+}
 
-    cmd.arg(chord);
-    cmd.assert().success().stdout(format!("{chart}\n"));
+#[test]
+fn test_name_verbose_json_conflict() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name")
+        .arg("--verbose")
+        .arg("--format")
+        .arg("json")
+        .arg("0003");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "cannot be used with --format json",
+    ));
 
     Ok(())
 }
 
 #[rstest(
     chart,
+    frets,
+    pitch_classes,
     names,
-    case("0000", "C6 - C major 6th\nAm7 - A minor 7th"),
-    case("0003", "C - C major"),
-    case("0013", "Csus4 - C suspended 4th\nFsus2 - F suspended 2nd"),
-    case("10 10 10 10", "Gm7 - G minor 7th\nA#6 - A# major 6th"),
-    case("7604", "Dmaj9 - D major 9th"),
-    case("1304", "Emaj13 - E major 13th")
+    case("0003", json!([0, 0, 0, 3]), json!(["C", "E", "G"]), json!(["C"])),
+    case("0013", json!([0, 0, 1, 3]), json!(["C", "F", "G"]), json!(["Csus4", "Fsus2"])),
+    case("x210", json!([null, 2, 1, 0]), json!(["D", "F", "A"]), json!(["Dm"])),
+    case("1234", json!([1, 2, 3, 4]), json!(["C#", "D", "G", "G#"]), json!([])),
 )]
-fn test_name(chart: &str, names: &'static str) -> Result<(), Box<dyn Error + 'static>> {
+fn test_name_json(
+    chart: &str,
+    frets: Value,
+    pitch_classes: Value,
+    names: Value,
+) -> Result<(), Box<dyn Error + 'static>> {
     let mut cmd = Command::cargo_bin("ukebox")?;
-    cmd.arg("name").arg(chart);
-    cmd.assert().success().stdout(format!("{names}\n"));
+    cmd.arg("name").arg("--format").arg("json").arg(chart);
+    let output = cmd.output()?;
+    assert!(output.status.success());
+
+    let json: Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["frets"], frets);
+    assert_eq!(json["tuning"], "C");
+    assert_eq!(json["pitch_classes"], pitch_classes);
+
+    let chord_names: Vec<_> = json["chords"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].clone())
+        .collect();
+    assert_eq!(Value::from(chord_names), names);
+
+    Ok(())
+}
+
+#[rstest(
+    chart,
+    open,
+    case("0003", true),
+    case("x210", true),
+    case("5433", false),
+    case("x232", false)
+)]
+fn test_name_json_open_string(chart: &str, open: bool) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("name").arg("--format").arg("json").arg(chart);
+    let output = cmd.output()?;
+    assert!(output.status.success());
+
+    let json: Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["contains_open_string"], open);
 
     Ok(())
 }
@@ -515,9 +2278,9 @@ fn test_name(chart: &str, names: &'static str) -> Result<(), Box<dyn Error + 'st
     case("0003", "C", "C - C major"),
     case("0003", "D", "D - D major"),
     case("0003", "G", "G - G major"),
-    case("10 10 10 10", "C", "Gm7 - G minor 7th\nA#6 - A# major 6th"),
+    case("10 10 10 10", "C", "A#6 - A# major 6th\nGm7 - G minor 7th"),
     case("10 10 10 10", "D", "C6 - C major 6th\nAm7 - A minor 7th"),
-    case("10 10 10 10", "G", "Dm7 - D minor 7th\nF6 - F major 6th")
+    case("10 10 10 10", "G", "F6 - F major 6th\nDm7 - D minor 7th")
 )]
 fn test_name_with_tuning(
     chart: &str,
@@ -533,6 +2296,70 @@ fn test_name_with_tuning(
     Ok(())
 }
 
+#[rstest(
+    chart1,
+    chart2,
+    output,
+    case(
+        "0003",
+        "0232",
+        indoc!("
+            A  ||---|---|-3-|---|- C
+            E o||---|---|---|---|- E
+            C o||---|---|---|---|- C
+            G o||---|---|---|---|- G
+
+            A  ||---|-2-|---|---|- B
+            E  ||---|---|-3-|---|- G
+            C  ||---|-1-|---|---|- D
+            G o||---|---|---|---|- G
+
+            Semitone distance: 6, fingering distance: 3
+        ")
+    ),
+    case(
+        "2220",
+        "2220",
+        indoc!("
+            A o||---|---|---|---|- A
+            E  ||---|-1-|---|---|- F#
+            C  ||---|-1-|---|---|- D
+            G  ||---|-1-|---|---|- A
+
+            A o||---|---|---|---|- A
+            E  ||---|-1-|---|---|- F#
+            C  ||---|-1-|---|---|- D
+            G  ||---|-1-|---|---|- A
+
+            Semitone distance: 0, fingering distance: 0
+        ")
+    ),
+    case(
+        "x232",
+        "0232",
+        indoc!("
+            A  ||---|-2-|---|---|- B
+            E  ||---|---|-3-|---|- G
+            C  ||---|-1-|---|---|- D
+            G x||---|---|---|---|-
+
+            A  ||---|-2-|---|---|- B
+            E  ||---|---|-3-|---|- G
+            C  ||---|-1-|---|---|- D
+            G o||---|---|---|---|- G
+
+            Semitone distance: 0, fingering distance: 0
+        ")
+    ),
+)]
+fn test_diff(chart1: &str, chart2: &str, output: &'static str) -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("diff").arg(chart1).arg(chart2);
+    cmd.assert().success().stdout(output);
+
+    Ok(())
+}
+
 #[rstest(
     chord_seq,
     option,
@@ -663,3 +2490,71 @@ fn test_voice_lead(
 
     Ok(())
 }
+
+#[rstest(
+    chord_seq1,
+    chord_seq2,
+    case("C | F | G", "C F G"),
+    case("| C F | | G |", "C F G"),
+    case("C F x2 | G", "C F C F G")
+)]
+fn test_voice_lead_bars(
+    chord_seq1: &str,
+    chord_seq2: &str,
+) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    let output = cmd.arg("voice-lead").arg(chord_seq2).output()?;
+
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("voice-lead").arg(chord_seq1);
+    cmd.assert().success().stdout(output.stdout);
+
+    Ok(())
+}
+
+#[test]
+fn test_voice_lead_invalid_token() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("voice-lead").arg("C | Hm | G");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid token 'Hm'"));
+
+    Ok(())
+}
+
+#[test]
+fn test_fretboard() -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("fretboard").arg("--max-fret").arg("5");
+    cmd.assert().success().stdout(indoc!(
+        "
+        0   1   2   3   4   5
+        A   A#  B   C   C#  D
+        E   F   F#  G   G#  A
+        C   C#  D   D#  E   F
+        G   G#  A   A#  B   C
+    "
+    ));
+
+    Ok(())
+}
+
+#[rstest(
+    tuning,
+    open_strings,
+    case("C", "A\nE\nC\nG\n"),
+    case("D", "B\nF#\nD\nA\n"),
+    case("G", "E\nB\nG\nD\n")
+)]
+fn test_fretboard_tuning(tuning: &str, open_strings: &str) -> Result<(), Box<dyn Error + 'static>> {
+    let mut cmd = Command::cargo_bin("ukebox")?;
+    cmd.arg("fretboard")
+        .arg("--max-fret")
+        .arg("0")
+        .arg("--tuning")
+        .arg(tuning);
+    cmd.assert().success().stdout(format!("0\n{open_strings}"));
+
+    Ok(())
+}